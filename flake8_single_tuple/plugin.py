@@ -1,29 +1,379 @@
 import ast
 import bisect
+import os
+import re
+import string
 import tokenize
-from typing import Generator, List, Optional, Tuple
+from collections import Counter
+from typing import Dict, FrozenSet, Generator, List, Optional, Set, Tuple, Union
+
+from flake8_single_tuple.project import ProjectIndex
+
+# DB-API placeholders: qmark `?`, format `%s` and numeric `:1` are positional;
+# named `:name` and pyformat `%(name)s` take a mapping instead.
+_SQL_QUOTED = re.compile(r"'(?:[^']|'')*'|\"(?:[^\"]|\"\")*\"")
+_SQL_POSITIONAL = re.compile(r"\?|%s|(?<!:):\d+\b")
+_SQL_NAMED = re.compile(r"(?<!:):[A-Za-z_]\w*|%\([^)]*\)s")
+
+# f-strings are tokenized piecewise from Python 3.12; absent before that.
+_FSTRING_START = getattr(tokenize, "FSTRING_START", None)
+_FSTRING_END = getattr(tokenize, "FSTRING_END", None)
+
+# One printf-style conversion: optional mapping key, flags, width, precision,
+# length modifier and conversion type. `*` width/precision consume arguments.
+_PRINTF_DIRECTIVE = re.compile(
+    r"%(?P<key>\([^)]*\))?[#0\- +]*(?P<width>\*|\d+)?(?:\.(?P<precision>\*|\d+))?[hlL]?(?P<conv>[diouxXeEfFgGcrsa%])"
+)
+
+# A word such as "admin", "user_id" or "read-only", as opposed to a set of
+# characters such as "0123456789abcdef" or "axrwb+t".
+_WORD = re.compile(r"[A-Za-z_]\w*(?:[-. ]\w+)*")
+
+# A callable's parameters as (name, annotation): those that can be passed
+# positionally, in order, and those that can be passed by keyword.
+Param = Tuple[str, Optional[ast.expr]]
+Signature = Tuple[List[Param], Dict[str, Optional[ast.expr]]]
+
+# A binding as (scope, dotted name). The scope is the Module, the function
+# whose locals hold the name, or the class for `self.x` / `cls.x`.
+BindingKey = Tuple[ast.AST, str]
+
+_SCOPE_NODES = (ast.FunctionDef, ast.AsyncFunctionDef, ast.Lambda, ast.ClassDef)
 
 
 class SingleTupleChecker(ast.NodeVisitor):
     name = "flake8-single-tuple"
     STC001 = "STC001 redundant or misleading parentheses; did you mean `(x,)` for a tuple?"
+    STC002 = "STC002 parenthesized string is iterated character by character; did you mean `(x,)`?"
+    STC003 = "STC003 parenthesized string passed to `{}` is consumed character by character; did you mean `(x,)`?"
+
+    # Callables known to consume an iterable argument, keyed by qualified name.
+    # Each entry is (positional indexes, keyword names); `None` positions means
+    # every positional argument is an iterable, as with `zip` or `chain`.
+    ITERABLE_CONSUMERS: Dict[str, Tuple[Optional[Tuple[int, ...]], FrozenSet[str]]] = {
+        "set": ((0,), frozenset()),
+        "frozenset": ((0,), frozenset()),
+        "list": ((0,), frozenset()),
+        "tuple": ((0,), frozenset()),
+        "sorted": ((0,), frozenset()),
+        "reversed": ((0,), frozenset()),
+        "enumerate": ((0,), frozenset({"iterable"})),
+        "zip": (None, frozenset()),
+        "filter": ((1,), frozenset()),
+        "dict.fromkeys": ((0,), frozenset({"iterable"})),
+        "str.join": ((0,), frozenset()),
+        "random.choice": ((0,), frozenset({"seq"})),
+        "random.choices": ((0,), frozenset({"population"})),
+        "random.sample": ((0,), frozenset({"population"})),
+        "itertools.chain": (None, frozenset()),
+        "itertools.cycle": ((0,), frozenset()),
+        "itertools.islice": ((0,), frozenset()),
+        "itertools.permutations": ((0,), frozenset({"iterable"})),
+        "itertools.combinations": ((0,), frozenset({"iterable"})),
+        "itertools.product": (None, frozenset()),
+        "collections.Counter": ((0,), frozenset({"iterable"})),
+        "collections.deque": ((0,), frozenset({"iterable"})),
+    }
+
+    STC004 = "STC004 parenthesized string adds {}, not 1 item; did you mean `(x,)`?"
+
+    # Methods that add (or remove) every element of their first argument.
+    # Called unbound on one of MUTATING_TYPES, the iterable is the second.
+    MUTATING_METHODS: FrozenSet[str] = frozenset({
+        "extend", "extendleft", "update", "difference_update",
+        "intersection_update", "symmetric_difference_update",
+    })
+    MUTATING_TYPES: FrozenSet[str] = frozenset({
+        "list", "set", "dict", "collections.deque", "collections.Counter",
+    })
+
+    STC005 = "STC005 `{call}` splits the string into characters; did you mean `{suggestion}`?"
+
+    # Suggested one-element literal for each container constructor.
+    # Character sets that read like words but are passed to set() on purpose.
+    KNOWN_CHARSETS: FrozenSet[str] = frozenset({
+        "aeiou", "AEIOU", "aeiouAEIOU", "aeiouy", "AEIOUY",
+        string.ascii_letters, string.ascii_lowercase, string.ascii_uppercase,
+    })
+
+    CONTAINER_CONSTRUCTORS: Dict[str, str] = {
+        "tuple": "({},)",
+        "list": "[{}]",
+        "set": "{{{}}}",
+        "frozenset": "frozenset({{{}}})",
+    }
+
+    STC006 = "STC006 destructuring a parenthesized string unpacks its characters ({}); did you mean `(x,)`?"
+
+    STC007 = "STC007 printf-style format with {} takes a parenthesized non-tuple operand; did you mean `(x,)`?"
+
+    STC008 = "STC008 implicit string concatenation in {}; missing comma?"
+
+    # Accidental one-tuples: a bare trailing comma where no tuple was meant.
+    STC009 = "STC009 trailing comma makes the assigned value a one-tuple; remove the comma or write `(x,)`"
+    STC010 = "STC010 trailing comma makes the return value a one-tuple; remove the comma or write `(x,)`"
+    STC011 = "STC011 trailing comma turns this expression statement into a one-tuple; remove the comma"
+
+    STC012 = "STC012 parenthesized element is not a tuple, unlike its {}-tuple siblings; did you mean `(x,)`?"
+
+    STC013 = "STC013 parenthesized dict value is not a tuple, unlike its {}-tuple siblings; did you mean `(x,)`?"
+
+    STC014 = "STC014 parenthesized class attribute is not a tuple, unlike its {}-tuple siblings; did you mean `(x,)`?"
+
+    # Bases whose class bodies declare uniform members or fields.
+    UNIFORM_CLASS_BASES: FrozenSet[str] = frozenset({
+        "enum.Enum", "enum.IntEnum", "enum.StrEnum", "enum.Flag", "enum.IntFlag",
+    })
+
+    STC015 = "STC015 parenthesized case pattern matches a single value, unlike its {}-element sequence siblings; did you mean `(x,)`?"
+    STC015_CAPTURE = "STC015 parenthesized capture pattern matches any subject, unlike its {}-element sequence siblings; did you mean `(x,)`?"
+
+    STC016 = (
+        "STC016 parenthesized argument is not a tuple, unlike the {arity}-tuple passed to "
+        "`{callee}` elsewhere (e.g. line {line}); did you mean `(x,)`?"
+    )
 
-    def __init__(self, tree: ast.AST, lines: list[str]):
+    STC001_USED = STC001 + " `{name}` is used as a container on line {line}"
+    STC017 = (
+        "STC017 `{name}` is a parenthesized string from {origin}, not a tuple, "
+        "so {consequence}; did you mean `(x,)` there?"
+    )
+
+    # Annotation bases and builtins whose values are known (non-string)
+    # containers, used to suppress membership reports on `x in (items)`.
+    CONTAINER_ANNOTATIONS: FrozenSet[str] = frozenset({
+        "list", "set", "dict", "frozenset", "tuple", "List", "Set", "Dict", "FrozenSet", "Tuple",
+        "Sequence", "MutableSequence", "Iterable", "Collection", "Container", "Mapping",
+        "MutableMapping", "AbstractSet", "MutableSet", "deque", "Deque", "defaultdict",
+        "DefaultDict", "OrderedDict", "Counter", "KeysView", "ValuesView", "ItemsView",
+    })
+    CONTAINER_RETURNING: FrozenSet[str] = frozenset({
+        "list", "set", "dict", "frozenset", "tuple", "sorted",
+        "collections.deque", "collections.defaultdict", "collections.OrderedDict", "collections.Counter",
+    })
+    CONTAINER_METHODS: FrozenSet[str] = frozenset({"keys", "values", "items", "split", "rsplit", "splitlines"})
+
+    STC018 = "STC018 parenthesized key is not a tuple, but `{name}` is keyed by tuples (line {line}); did you mean `(x,)`?"
+
+    # Lookups whose first argument is a key of the receiving dict/set.
+    KEY_METHODS: FrozenSet[str] = frozenset({"get", "pop", "setdefault"})
+
+    STC019 = "STC019 parenthesized value is not a tuple, but it is annotated `{}`; did you mean `(x,)`?"
+    STC019_ARITY = "STC019 parenthesized value is not a tuple, but it is annotated `{}`; expected a {}-tuple"
+
+    # Annotations that make a parenthesized single value a missed comma, and
+    # the iterator annotations whose first parameter types each `yield`.
+    SEQUENCE_ANNOTATIONS: FrozenSet[str] = frozenset({
+        "tuple", "Tuple", "Sequence", "Iterable", "set", "frozenset", "Set", "FrozenSet",
+    })
+    ITERATOR_ANNOTATIONS: FrozenSet[str] = frozenset({
+        "Iterator", "Iterable", "Generator", "AsyncIterator", "AsyncIterable", "AsyncGenerator",
+    })
+
+    STC020 = "STC020 parenthesized string passed to `{callee}` parameter `{param}: {annotation}`; did you mean `(x,)`?"
+    # Parameter annotations taking a sequence of items. Mappings are left out:
+    # `(x,)` is no fix for a `dict[...]` parameter.
+    PARAMETER_SEQUENCE_ANNOTATIONS: FrozenSet[str] = SEQUENCE_ANNOTATIONS | frozenset({
+        "list", "List", "MutableSequence", "Collection", "AbstractSet", "MutableSet", "deque", "Deque",
+    })
+
+    # Module and class dunders that must be sequences of names, with the
+    # concrete runtime consequence of a parenthesized non-tuple for each.
+    DUNDER_SEQUENCES: Dict[str, str] = {
+        "__all__": (
+            "STC021 `__all__` is parenthesized but not a tuple, so `from ... import *` "
+            "treats a string as one name per character; did you mean `(x,)`?"
+        ),
+        "__slots__": (
+            "STC022 `__slots__` is parenthesized but not a tuple, so a lone string only "
+            "works as a single slot and breaks as soon as a second one is added; did you mean `(x,)`?"
+        ),
+        "__match_args__": (
+            "STC023 `__match_args__` is parenthesized but not a tuple, so positional class "
+            "patterns like `case C(x)` raise TypeError; did you mean `(x,)`?"
+        ),
+    }
+
+    STC024 = "STC024 parenthesized `{method}` parameters are not a tuple, but the SQL has {placeholders}; did you mean `{suggestion}`?"
+
+    # Cursor methods taking (sql, parameters); `executemany` takes a sequence of rows.
+    DBAPI_METHODS: FrozenSet[str] = frozenset({"execute", "executemany", "mogrify"})
+
+    # Django rule pack: well-known attributes that Django iterates.
+    STC101 = "STC101 Django `{}` is parenthesized but not a tuple, so Django iterates it character by character; did you mean `(x,)`?"
+    STC102 = "STC102 Django `{}` wraps a single group in redundant parentheses; did you mean `((...),)`?"
+    STC103 = "STC103 Django setting `{}` is parenthesized but not a tuple, so each character is read as an entry; did you mean `(x,)`?"
+
+    DJANGO_META_ATTRIBUTES: FrozenSet[str] = frozenset({
+        "ordering", "unique_together", "index_together", "fields", "exclude", "permissions",
+        "default_permissions", "required_db_features", "read_only_fields",
+    })
+    DJANGO_ADMIN_ATTRIBUTES: FrozenSet[str] = frozenset({
+        "list_display", "list_display_links", "list_filter", "list_editable", "list_select_related",
+        "search_fields", "readonly_fields", "fields", "exclude", "ordering", "raw_id_fields",
+        "autocomplete_fields", "filter_horizontal", "filter_vertical", "actions", "inlines",
+    })
+    DJANGO_VIEW_ATTRIBUTES: FrozenSet[str] = frozenset({
+        "permission_classes", "authentication_classes", "renderer_classes", "parser_classes",
+        "filter_backends", "throttle_classes", "http_method_names", "search_fields",
+        "ordering_fields", "filterset_fields",
+    })
+    DJANGO_ADMIN_BASES: FrozenSet[str] = frozenset({"ModelAdmin", "InlineModelAdmin", "TabularInline", "StackedInline"})
+    DJANGO_VIEW_BASES: FrozenSet[str] = frozenset({"APIView", "GenericAPIView", "ViewSet", "GenericViewSet", "ModelViewSet"})
+    # A nested-tuple setting where `(("a", "b"))` is one group, not a group of one.
+    DJANGO_GROUPED_ATTRIBUTES: FrozenSet[str] = frozenset({"unique_together", "index_together", "permissions"})
+    # Attributes that also accept the string `"__all__"`.
+    DJANGO_ALL_ATTRIBUTES: FrozenSet[str] = frozenset({"fields", "exclude"})
+    DJANGO_SETTINGS: FrozenSet[str] = frozenset({
+        "INSTALLED_APPS", "MIDDLEWARE", "MIDDLEWARE_CLASSES", "ALLOWED_HOSTS", "AUTHENTICATION_BACKENDS",
+        "STATICFILES_DIRS", "STATICFILES_FINDERS", "TEMPLATE_DIRS", "LOCALE_PATHS", "FIXTURE_DIRS",
+        "PASSWORD_HASHERS", "CSRF_TRUSTED_ORIGINS", "INTERNAL_IPS",
+    })
+
+    # pytest rule pack: parametrize/fixture argument values.
+    STC201 = "STC201 parenthesized `parametrize` argvalues is not a tuple, so each character becomes a test case; did you mean `(x,)`?"
+    STC202 = "STC202 parenthesized fixture `params` is not a tuple, so each character becomes a fixture param; did you mean `(x,)`?"
+    STC203 = "STC203 `parametrize` row has {found}, but {expected} argnames are declared"
+
+    # Web framework rule pack: route registration on Flask, FastAPI and Starlette.
+    STC301 = "STC301 parenthesized route `methods` is not a tuple, so each character is registered as an HTTP method; did you mean `(x,)`?"
+    STC302 = "STC302 parenthesized `{}` is not a tuple, so the framework iterates it character by character; did you mean `(x,)`?"
+
+    # Calls that create an app or router; names bound to them are route receivers.
+    WEB_APP_FACTORIES: FrozenSet[str] = frozenset({
+        "flask.Flask", "flask.Blueprint", "fastapi.FastAPI", "fastapi.APIRouter",
+        "starlette.applications.Starlette", "starlette.routing.Router",
+    })
+    WEB_ROUTE_CONSTRUCTORS: FrozenSet[str] = frozenset({
+        "starlette.routing.Route", "starlette.routing.WebSocketRoute", "starlette.routing.Mount",
+        "fastapi.routing.APIRoute", "fastapi.Security", "fastapi.security.SecurityScopes",
+    })
+    WEB_APP_METHODS: FrozenSet[str] = frozenset({
+        "route", "add_url_rule", "api_route", "add_api_route", "add_route", "websocket",
+        "add_websocket_route", "include_router", "add_middleware",
+        "get", "post", "put", "patch", "delete", "head", "options", "trace",
+    })
+    WEB_SEQUENCE_KEYWORDS: FrozenSet[str] = frozenset({
+        "tags", "dependencies", "scopes", "routes", "middleware",
+        "allow_origins", "allow_methods", "allow_headers", "expose_headers",
+    })
+    WEB_VIEW_BASES: FrozenSet[str] = frozenset({"flask.views.View", "flask.views.MethodView"})
+
+    # CLI rule pack: argparse and click choices.
+    STC401 = "STC401 parenthesized argparse `{}` is not a tuple, so argparse iterates it character by character; did you mean `(x,)`?"
+    STC402 = "STC402 parenthesized `Choice` choices are not a tuple, so each character is a choice and the whole word is rejected; did you mean `(x,)`?"
+    STC403 = "STC403 parenthesized `default` of a `multiple=True` option is not a tuple, so each character becomes a value; did you mean `(x,)`?"
+
+    # argparse method -> keyword arguments it iterates.
+    ARGPARSE_SEQUENCE_KEYWORDS: Dict[str, FrozenSet[str]] = {
+        "add_argument": frozenset({"choices"}),
+        "add_parser": frozenset({"aliases"}),
+    }
+    CLICK_CHOICE_TYPES: FrozenSet[str] = frozenset({"click.Choice", "click.types.Choice"})
+    CLICK_OPTION_FACTORIES: FrozenSet[str] = frozenset({"click.option", "click.Option", "click.core.Option"})
+
+    # Opt-in project mode: resolve imported constants against every module
+    # under this root. Set from `--single-tuple-project-root`.
+    project_root: Optional[str] = None
+
+    # Opt-in framework rule packs, from `--single-tuple-packs`.
+    AVAILABLE_PACKS: FrozenSet[str] = frozenset({"cli", "django", "pytest", "web"})
+    enabled_packs: FrozenSet[str] = frozenset()
+
+    def __init__(self, tree: ast.AST, lines: list[str], filename: str = "stdin"):
         self.tree = tree
         self.lines = lines
+        self.filename = filename
+        self.source = "".join(lines)
         self.tokens: list = []
         self.token_starts: List[Tuple[int, int]] = []
         self.violations: list[Tuple[int, int, str, type]] = []
+        self.import_aliases: Dict[str, str] = {}
+        self.bound_names: FrozenSet[str] = frozenset()
+        self.reported_nodes: Set[ast.AST] = set()
+        self.call_sites: Dict[str, List[ast.Call]] = {}
+        self.scope_of: Dict[ast.AST, ast.AST] = {}
+        self.scope_parents: Dict[ast.AST, Optional[ast.AST]] = {}
+        self.scope_locals: Dict[ast.AST, Set[str]] = {}
+        self.scope_globals: Dict[ast.AST, Set[str]] = {}
+        self.string_bindings: Dict[BindingKey, ast.expr] = {}
+        self.used_bindings: Dict[ast.AST, Tuple[str, int]] = {}
+        self.imported_names: Dict[str, str] = {}
+        self.project_index: Optional[ProjectIndex] = None
+        self.value_kinds: Dict[str, Optional[str]] = {}
+        self.return_kinds: Dict[str, Optional[str]] = {}
+        self.key_kinds: Dict[BindingKey, Tuple[str, int]] = {}
+        self.signatures: Dict[str, Optional[Signature]] = {}
+        self.class_level_methods: Set[str] = set()
+        self.instances: Dict[BindingKey, str] = {}
+        self.web_apps: FrozenSet[str] = frozenset()
+        self.tuple_returns: Set[ast.Return] = set()
 
-    def run(self) -> Generator[Tuple[int, int, str, type], None, None]:
+    @classmethod
+    def add_options(cls, parser) -> None:
+        parser.add_option(
+            "--single-tuple-project-root",
+            default=None,
+            parse_from_config=True,
+            help="Enable project mode: resolve imported constants against the "
+            "modules under this directory (default: off)",
+        )
+        parser.add_option(
+            "--single-tuple-packs",
+            default="",
+            parse_from_config=True,
+            comma_separated_list=True,
+            help="Comma-separated framework rule packs to enable. "
+            f"Available: {', '.join(sorted(cls.AVAILABLE_PACKS))} (default: none)",
+        )
+
+    @classmethod
+    def parse_options(cls, options) -> None:
+        cls.project_root = options.single_tuple_project_root
+        cls.enabled_packs = frozenset(options.single_tuple_packs) & cls.AVAILABLE_PACKS
+
+    @classmethod
+    def module_string_bindings(cls, tree: ast.AST, lines: List[str]) -> Dict[str, int]:
+        """
+        Module-level names bound to a parenthesized string, with their line.
+        Used to build the project index.
+        """
+        checker = cls(tree, lines)
+        if not checker._tokenize():
+            return {}
+        checker._collect_scopes()
+        top_level = {id(stmt.value) for stmt in getattr(tree, "body", []) if isinstance(stmt, (ast.Assign, ast.AnnAssign))}
+        return {
+            name: value.lineno
+            for (scope, name), value in checker._collect_string_bindings().items()
+            if scope is tree and "." not in name and id(value) in top_level
+        }
+
+    def _tokenize(self) -> bool:
         line_iter = iter(self.lines)
         try:
             self.tokens = list(tokenize.generate_tokens(lambda: next(line_iter)))
         except (tokenize.TokenError, StopIteration):
+            return False
+        self.token_starts = [t.start for t in self.tokens]
+        return True
+
+    def run(self) -> Generator[Tuple[int, int, str, type], None, None]:
+        if not self._tokenize():
             return
 
-        self.token_starts = [t.start for t in self.tokens]
+        self.import_aliases = self._collect_import_aliases()
+        self.bound_names = self._collect_bound_names()
+        self._collect_scopes()
+        self.string_bindings = self._collect_string_bindings()
+        self._collect_value_kinds()
+        self.key_kinds = self._collect_key_kinds()
+        self.signatures = self._collect_signatures()
+        self.instances = self._collect_instances()
+        self.project_index = self._load_project_index()
+        self._check_binding_uses()
         self.visit(self.tree)
+        self._check_call_site_shapes()
         yield from self.violations
 
     # ------------------------------------------------------------------
@@ -31,55 +381,1573 @@ class SingleTupleChecker(ast.NodeVisitor):
     # ------------------------------------------------------------------
 
     def visit_Assign(self, node: ast.Assign) -> None:
+        for target in node.targets:
+            self._check_dunder_sequence(target, node.value)
+
+        unpacking = [t for t in node.targets if isinstance(t, (ast.Tuple, ast.List))]
+        dunder = any(self._dunder_message(t) for t in node.targets)
+        if not unpacking and not dunder:
+            self._check_trailing_comma(node.value, self.STC009)
+
         # Only flag bare string literal assignments: x = ("foo") or x = (f"...")
-        if isinstance(node.value, ast.Constant) and isinstance(node.value.value, str):
-            self._check_candidate(node.value, in_membership=False)
-        elif isinstance(node.value, ast.JoinedStr):  # f-string
-            self._check_candidate(node.value, in_membership=False)
+        if self._is_string_literal(node.value):
+            if unpacking:
+                message = self._destructuring_message(unpacking[0], node.value)
+                self._check_candidate(node.value, in_membership=False, message=message)
+            else:
+                self._check_candidate(node.value, in_membership=False, message=self._binding_message(node.value))
         self.generic_visit(node)
 
     def visit_AnnAssign(self, node: ast.AnnAssign) -> None:
         if node.value is None:
             self.generic_visit(node)
             return
-        if isinstance(node.value, ast.Constant) and isinstance(node.value.value, str):
-            self._check_candidate(node.value, in_membership=False)
-        elif isinstance(node.value, ast.JoinedStr):
-            self._check_candidate(node.value, in_membership=False)
+        self._check_dunder_sequence(node.target, node.value)
+        intended = self._is_sequence_annotation(self._parse_string_annotation(node.annotation))
+        if not intended and not self._dunder_message(node.target):
+            self._check_trailing_comma(node.value, self.STC009)
+        self._check_annotated_value(node.annotation, node.value)
+        if self._is_string_literal(node.value):
+            self._check_candidate(node.value, in_membership=False, message=self._binding_message(node.value))
+        self.generic_visit(node)
+
+    def visit_FunctionDef(self, node: ast.FunctionDef) -> None:
+        self._check_annotated_function(node)
+        self.generic_visit(node)
+
+    def visit_AsyncFunctionDef(self, node: ast.AsyncFunctionDef) -> None:
+        self._check_annotated_function(node)
+        self.generic_visit(node)
+
+    def visit_Module(self, node: ast.Module) -> None:
+        if "django" in self.enabled_packs and self._is_django_settings(node):
+            for target, value in self._simple_assignments(node.body):
+                if target in self.DJANGO_SETTINGS:
+                    self._check_pack_value(value, self.STC103.format(target))
+        if "web" in self.enabled_packs:
+            self.web_apps = self._collect_web_apps()
+        self.generic_visit(node)
+
+    def visit_ClassDef(self, node: ast.ClassDef) -> None:
+        if "django" in self.enabled_packs:
+            self._check_django_class(node)
+        if "web" in self.enabled_packs:
+            self._check_web_view(node)
+        self._check_class_body_shapes(node)
+        self.generic_visit(node)
+
+    def visit_Match(self, node: "ast.Match") -> None:
+        self._check_case_pattern_shapes(node)
+        self.generic_visit(node)
+
+    def visit_Return(self, node: ast.Return) -> None:
+        if node not in self.tuple_returns:
+            self._check_trailing_comma(node.value, self.STC010)
+        self.generic_visit(node)
+
+    def visit_Expr(self, node: ast.Expr) -> None:
+        self._check_trailing_comma(node.value, self.STC011)
         self.generic_visit(node)
 
     def visit_Compare(self, node: ast.Compare) -> None:
         has_membership = any(isinstance(op, (ast.In, ast.NotIn)) for op in node.ops)
 
         if has_membership:
-            self._check_candidate(node.left, in_membership=True)
+            self._check_membership_lhs(node)
+            self._check_implicit_join(node.left, "membership test")
 
         for op, comp in zip(node.ops, node.comparators):
             if isinstance(op, (ast.In, ast.NotIn)):
-                self._check_candidate(comp, in_membership=True)
+                self._check_membership_side(comp)
+                self._check_implicit_join(comp, "membership test")
+
+        self.generic_visit(node)
 
+    # Iteration contexts: `for c in ("abc")`, `[c for c in ("abc")]`,
+    # `yield from ("abc")` and `f(*("abc"))` all walk the characters of the
+    # string. Only string literals are considered — a parenthesized name or
+    # call is ordinary grouping here.
+
+    def visit_For(self, node: ast.For) -> None:
+        self._check_iterated(node.iter)
+        self.generic_visit(node)
+
+    def visit_AsyncFor(self, node: ast.AsyncFor) -> None:
+        self._check_iterated(node.iter)
+        self.generic_visit(node)
+
+    def visit_comprehension(self, node: ast.comprehension) -> None:
+        self._check_iterated(node.iter)
+        self.generic_visit(node)
+
+    def visit_YieldFrom(self, node: ast.YieldFrom) -> None:
+        self._check_iterated(node.value)
+        self.generic_visit(node)
+
+    def visit_Starred(self, node: ast.Starred) -> None:
+        self._check_iterated(node.value)
+        self.generic_visit(node)
+
+    def visit_AugAssign(self, node: ast.AugAssign) -> None:
+        # items += ("foo") extends a list by characters; onto a str it is plain concatenation.
+        if isinstance(node.op, ast.Add):
+            self._check_dunder_sequence(node.target, node.value)
+        if (
+            isinstance(node.op, ast.Add)
+            and self._is_string_literal(node.value)
+            and self._infer_kind(node.target) != "string"
+        ):
+            self._check_candidate(node.value, in_membership=False, message=self._mutation_message(node.value))
+        self.generic_visit(node)
+
+    def visit_List(self, node: ast.List) -> None:
+        self._check_collection_elements(node)
+        self.generic_visit(node)
+
+    def visit_Tuple(self, node: ast.Tuple) -> None:
+        self._check_collection_elements(node)
+        self.generic_visit(node)
+
+    def visit_Set(self, node: ast.Set) -> None:
+        self._check_collection_elements(node)
+        self.generic_visit(node)
+
+    def visit_Dict(self, node: ast.Dict) -> None:
+        # `**mapping` entries have a None key and are not values of their own.
+        values = [v for k, v in zip(node.keys, node.values) if k is not None]
+        self._check_shape_outliers(values, self.STC013)
+        self.generic_visit(node)
+
+    def visit_Subscript(self, node: ast.Subscript) -> None:
+        self._check_tuple_key(node.value, node.slice)
         self.generic_visit(node)
 
+    def visit_BinOp(self, node: ast.BinOp) -> None:
+        if isinstance(node.op, ast.Mod):
+            self._check_printf_operand(node)
+        self.generic_visit(node)
+
+    def visit_Call(self, node: ast.Call) -> None:
+        self._check_iterable_consumer(node)
+        self._check_collection_mutation(node)
+        self._check_container_constructor(node)
+        self._check_dict_call_values(node)
+        self._check_key_method(node)
+        self._check_signature_arguments(node)
+        self._check_dbapi_parameters(node)
+        if "pytest" in self.enabled_packs:
+            self._check_pytest_call(node)
+        if "web" in self.enabled_packs:
+            self._check_web_call(node)
+        if "cli" in self.enabled_packs:
+            self._check_cli_call(node)
+        qualname = self._qualified_name(node.func)
+        if qualname is not None:
+            self.call_sites.setdefault(qualname, []).append(node)
+        self.generic_visit(node)
+
+    # ------------------------------------------------------------------
+    # Call resolution
+    # ------------------------------------------------------------------
+
+    def _collect_import_aliases(self) -> Dict[str, str]:
+        """
+        Map local names bound by `import x [as y]` / `from x import y [as z]`
+        anywhere in the module to their qualified names. Relative imports are
+        left unresolved.
+        """
+        aliases: Dict[str, str] = {}
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Import):
+                for alias in node.names:
+                    if alias.asname:
+                        aliases[alias.asname] = alias.name
+                    else:
+                        head = alias.name.split(".")[0]
+                        aliases[head] = head
+            elif isinstance(node, ast.ImportFrom) and node.module and not node.level:
+                for alias in node.names:
+                    aliases[alias.asname or alias.name] = f"{node.module}.{alias.name}"
+        return aliases
+
+    def _collect_bound_names(self) -> FrozenSet[str]:
+        """
+        Every name the module binds anywhere — assignments, definitions,
+        parameters and imports. Used to tell whether a builtin is shadowed.
+        """
+        names = set(self.import_aliases)
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Name) and isinstance(node.ctx, (ast.Store, ast.Del)):
+                names.add(node.id)
+            elif isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef, ast.ClassDef)):
+                names.add(node.name)
+            elif isinstance(node, ast.arg):
+                names.add(node.arg)
+        return frozenset(names)
+
+    def _qualified_name(self, func: ast.expr) -> Optional[str]:
+        """
+        Resolve a call target to a dotted name through the module's import
+        aliases. Methods on string literals resolve under `str`, so
+        `", ".join(...)` becomes `str.join`.
+        """
+        parts: List[str] = []
+        while isinstance(func, ast.Attribute):
+            parts.append(func.attr)
+            func = func.value
+        if isinstance(func, ast.Name):
+            parts.append(self.import_aliases.get(func.id, func.id))
+        elif isinstance(func, ast.Constant) and isinstance(func.value, str):
+            parts.append("str")
+        else:
+            return None
+        return ".".join(reversed(parts))
+
+    def _call_arguments(
+        self,
+        node: ast.Call,
+        positions: Optional[Tuple[int, ...]],
+        keywords: FrozenSet[str],
+    ) -> List[ast.expr]:
+        args: List[ast.expr] = []
+        for i, arg in enumerate(node.args):
+            if isinstance(arg, ast.Starred):
+                break
+            if positions is None or i in positions:
+                args.append(arg)
+        args.extend(kw.value for kw in node.keywords if kw.arg in keywords)
+        return args
+
+    def _check_call_argument(self, call: ast.Call, arg: ast.expr, message: str, in_membership: bool = False) -> None:
+        """
+        Like `_check_candidate`, but ignores the call's own parentheses: in
+        `set("abc")` the `(` before the argument belongs to the call, not to
+        a would-be tuple.
+        """
+        if arg in self.reported_nodes:
+            return
+        violation_idx = self._find_candidate_violation(arg, in_membership)
+        if violation_idx is None or violation_idx == self._call_open_paren_idx(call):
+            return
+        self.reported_nodes.add(arg)
+        self._report(violation_idx, message)
+
+    def _call_open_paren_idx(self, call: ast.Call) -> Optional[int]:
+        func = call.func
+        end_lineno = getattr(func, "end_lineno", func.lineno)
+        end_col = getattr(func, "end_col_offset", func.col_offset)
+        after_idx = self._find_token_idx(end_lineno, end_col, exact=False)
+        if after_idx is None:
+            return None
+        tok, idx = self._next_meaningful(after_idx - 1, 1)
+        if tok is None or tok.string != "(":
+            return None
+        return idx
+
+    # ------------------------------------------------------------------
+    # Binding table
+    # ------------------------------------------------------------------
+
+    @staticmethod
+    def _dotted_name(node: ast.AST) -> Optional[str]:
+        parts: List[str] = []
+        while isinstance(node, ast.Attribute):
+            parts.append(node.attr)
+            node = node.value
+        if not isinstance(node, ast.Name):
+            return None
+        parts.append(node.id)
+        return ".".join(reversed(parts))
+
+    def _collect_scopes(self) -> None:
+        """
+        Record the scope of every node and the names each scope binds, so a
+        use can be matched to the binding it actually refers to. The module,
+        functions, lambdas and class bodies are scopes; comprehensions are
+        folded into their enclosing scope.
+        """
+        self.scope_parents[self.tree] = None
+        stack: List[Tuple[ast.AST, ast.AST]] = [(self.tree, self.tree)]
+        while stack:
+            node, scope = stack.pop()
+            self.scope_of[node] = scope
+            bound = self.scope_locals.setdefault(scope, set())
+            if isinstance(node, ast.Name) and isinstance(node.ctx, ast.Store):
+                bound.add(node.id)
+            elif isinstance(node, (ast.Global, ast.Nonlocal)):
+                self.scope_globals.setdefault(scope, set()).update(node.names)
+            elif isinstance(node, (ast.Import, ast.ImportFrom)):
+                bound.update((a.asname or a.name).split(".")[0] for a in node.names)
+            elif isinstance(node, ast.arg) and scope is not self.tree:
+                bound.add(node.arg)
+            if isinstance(node, _SCOPE_NODES) and node is not scope:
+                if not isinstance(node, ast.Lambda):
+                    bound.add(node.name)
+                self.scope_parents[node] = scope
+                self.scope_locals.setdefault(node, set())
+                stack.extend((child, node) for child in ast.iter_child_nodes(node))
+            else:
+                stack.extend((child, scope) for child in ast.iter_child_nodes(node))
+
+    def _binding_key(self, node: ast.expr) -> Optional[BindingKey]:
+        """
+        The scope that binds `node`'s root name, paired with its dotted name.
+        `self.x` / `cls.x` belong to the enclosing class; anything else
+        follows Python's lookup: local, enclosing functions, module.
+        """
+        name = self._dotted_name(node)
+        scope = self.scope_of.get(node)
+        if name is None or scope is None:
+            return None
+        root = name.split(".")[0]
+        if root in ("self", "cls") and "." in name:
+            owner = self._enclosing_class(scope)
+            if owner is not None:
+                return owner, name
+        if root in self.scope_globals.get(scope, ()):
+            return self.tree, name
+        current: Optional[ast.AST] = scope
+        while current is not None and current is not self.tree:
+            if root in self.scope_locals.get(current, ()):
+                return current, name
+            # Class bodies are not visible from the functions nested in them.
+            current = self.scope_parents.get(current)
+            while isinstance(current, ast.ClassDef):
+                current = self.scope_parents.get(current)
+        return self.tree, name
+
+    def _enclosing_class(self, scope: ast.AST) -> Optional[ast.ClassDef]:
+        """The class whose method (possibly nested) `scope` is, if any."""
+        current: Optional[ast.AST] = scope
+        while current is not None and not isinstance(self.scope_parents.get(current), ast.ClassDef):
+            current = self.scope_parents.get(current)
+        return None if current is None else self.scope_parents[current]
+
+    def _collect_string_bindings(self) -> Dict[BindingKey, ast.expr]:
+        """
+        Names and attributes bound exactly once in their scope, to a
+        parenthesized string literal: `ALLOWED = ("admin")`. Anything bound
+        more than once (including as a parameter) is dropped as ambiguous.
+        """
+        store_counts: Counter = Counter()
+        candidates: Dict[BindingKey, ast.expr] = {}
+        for node in ast.walk(self.tree):
+            if isinstance(node, (ast.Name, ast.Attribute)) and isinstance(node.ctx, ast.Store):
+                store_counts[self._binding_key(node)] += 1
+            elif isinstance(node, ast.arg) and node in self.scope_of:
+                store_counts[(self.scope_of[node], node.arg)] += 1
+
+            if isinstance(node, ast.Assign) and len(node.targets) == 1:
+                target = node.targets[0]
+            elif isinstance(node, ast.AnnAssign) and node.value is not None:
+                target = node.target
+            else:
+                continue
+            key = self._binding_key(target)
+            if key is None or not self._is_string_literal(node.value):
+                continue
+            if self._find_candidate_violation(node.value, in_membership=False) is not None:
+                candidates[key] = node.value
+        return {key: value for key, value in candidates.items() if store_counts[key] == 1}
+
+    def _binding_uses(self) -> Generator[Tuple[ast.expr, str], None, None]:
+        """Yield (expression, consequence) for every container-style use site."""
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Compare):
+                for op, comp in zip(node.ops, node.comparators):
+                    if isinstance(op, (ast.In, ast.NotIn)):
+                        yield comp, "this membership test is a substring search"
+            elif isinstance(node, (ast.For, ast.AsyncFor, ast.comprehension)):
+                yield node.iter, "this loop iterates its characters"
+            elif isinstance(node, ast.Call):
+                qualname = self._qualified_name(node.func)
+                if qualname in self.ITERABLE_CONSUMERS:
+                    positions, keywords = self.ITERABLE_CONSUMERS[qualname]
+                    for arg in self._call_arguments(node, positions, keywords):
+                        yield arg, f"`{qualname}` consumes its characters"
+
+    def _check_binding_uses(self) -> None:
+        """
+        Report each use of a tracked string binding as a container, pointing
+        back at the defining line. The definition's own STC001 report then
+        mentions the first use (see `_binding_message`). In project mode,
+        names imported from other modules are resolved through the index.
+        """
+        if not self.string_bindings and self.project_index is None:
+            return
+        for expr, consequence in self._binding_uses():
+            key = self._binding_key(expr)
+            if key is None or not isinstance(expr.ctx, ast.Load):
+                continue
+            scope, name = key
+            if key in self.string_bindings:
+                value = self.string_bindings[key]
+                if value not in self.used_bindings or expr.lineno < self.used_bindings[value][1]:
+                    self.used_bindings[value] = (name, expr.lineno)
+                origin = f"line {value.lineno}"
+            elif scope is self.tree:
+                resolved = self._resolve_imported_binding(name)
+                if resolved is None:
+                    continue
+                path, line = resolved
+                origin = f"{os.path.relpath(path, self.project_index.root)} line {line}"
+            else:
+                continue
+            message = self.STC017.format(name=name, origin=origin, consequence=consequence)
+            # `role in (ALLOWED)` gets STC017 only, not STC001 for its parens too.
+            self.reported_nodes.add(expr)
+            self._report_node(expr, message)
+
+    # ------------------------------------------------------------------
+    # Value kind inference
+    # ------------------------------------------------------------------
+
+    def _collect_value_kinds(self) -> None:
+        """
+        Infer, per dotted name, whether the module only ever binds it to a
+        "container" or a "string". Any other binding — an unknown value, an
+        unannotated parameter, a loop target — makes the name unknown (None).
+        Function return annotations are recorded the same way by name.
+        """
+        kinds: Dict[str, Optional[str]] = {}
+        returns: Dict[str, Optional[str]] = {}
+        inferred_targets: Set[ast.AST] = set()
+
+        def record(table: Dict[str, Optional[str]], name: Optional[str], kind: Optional[str]) -> None:
+            if name is not None:
+                table[name] = kind if table.get(name, kind) == kind else None
+
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Assign) and len(node.targets) == 1:
+                target = node.targets[0]
+                if isinstance(target, (ast.Name, ast.Attribute)):
+                    record(kinds, self._dotted_name(target), self._infer_kind(node.value))
+                    inferred_targets.add(target)
+            elif isinstance(node, ast.AnnAssign):
+                kind = self._annotation_kind(node.annotation)
+                if kind is None and node.value is not None:
+                    kind = self._infer_kind(node.value)
+                record(kinds, self._dotted_name(node.target), kind)
+                inferred_targets.add(node.target)
+            elif isinstance(node, ast.AugAssign) and isinstance(node.target, (ast.Name, ast.Attribute)):
+                # `s += "x"` leaves the kind to the other bindings; anything else makes it unknown.
+                if not (isinstance(node.op, ast.Add) and self._infer_kind(node.value) == "string"):
+                    record(kinds, self._dotted_name(node.target), None)
+                inferred_targets.add(node.target)
+            elif isinstance(node, ast.arg):
+                kind = self._annotation_kind(node.annotation) if node.annotation is not None else None
+                record(kinds, node.arg, kind)
+            elif isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)):
+                kind = self._annotation_kind(node.returns) if node.returns is not None else None
+                record(returns, node.name, kind)
+
+        for node in ast.walk(self.tree):
+            if isinstance(node, (ast.Name, ast.Attribute)) and isinstance(node.ctx, ast.Store):
+                if node not in inferred_targets:
+                    record(kinds, self._dotted_name(node), None)
+
+        self.value_kinds = kinds
+        self.return_kinds = returns
+
+    def _annotation_kind(self, annotation: ast.expr) -> Optional[str]:
+        """`list[str]` / `Set[str]` -> "container", `str` -> "string"."""
+        annotation = self._unwrap_optional(annotation)
+        if isinstance(annotation, ast.Subscript):
+            annotation = annotation.value
+        name = self._dotted_name(annotation)
+        if name is None:
+            return None
+        base = name.rsplit(".", 1)[-1]
+        if base in self.CONTAINER_ANNOTATIONS:
+            return "container"
+        if base == "str":
+            return "string"
+        return None
+
+    @staticmethod
+    def _is_none(node: ast.expr) -> bool:
+        return isinstance(node, ast.Constant) and node.value is None
+
+    def _infer_kind(self, node: ast.expr) -> Optional[str]:
+        if isinstance(node, (ast.List, ast.Set, ast.Dict, ast.Tuple, ast.ListComp, ast.SetComp, ast.DictComp)):
+            return "container"
+        if self._is_string_literal(node):
+            return "string"
+        if isinstance(node, (ast.Name, ast.Attribute)):
+            return self.value_kinds.get(self._dotted_name(node) or "")
+        if isinstance(node, ast.Call):
+            func = node.func
+            qualname = self._qualified_name(func)
+            # Bare builtins only count when the module doesn't rebind them.
+            builtin = qualname is not None and "." not in qualname and qualname not in self.bound_names
+            if qualname in self.CONTAINER_RETURNING and ("." in qualname or builtin):
+                return "container"
+            if qualname == "str" and builtin:
+                return "string"
+            if isinstance(func, ast.Attribute) and func.attr in self.CONTAINER_METHODS:
+                return "container"
+            if isinstance(func, ast.Name):
+                return self.return_kinds.get(func.id)
+            if isinstance(func, ast.Attribute):
+                return self.return_kinds.get(func.attr)
+            return None
+        if isinstance(node, ast.BinOp):
+            kinds = {self._infer_kind(node.left), self._infer_kind(node.right)}
+            if "container" in kinds:
+                return "container"
+            if "string" in kinds:
+                return "string"
+        return None
+
+    def _collect_key_kinds(self) -> Dict[BindingKey, Tuple[str, int]]:
+        """
+        Dicts and sets whose keys are consistently tuples or consistently
+        scalars, with the line of the first binding. A name qualifies only if
+        every binding in its scope is a dict/set literal or comprehension;
+        `d[a, b] = ...` stores add tuple-key evidence too.
+        """
+        shapes: Dict[BindingKey, Set[str]] = {}
+        lines: Dict[BindingKey, int] = {}
+        literal_targets: Set[ast.AST] = set()
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Assign) and len(node.targets) == 1:
+                target, value = node.targets[0], node.value
+            elif isinstance(node, ast.AnnAssign) and node.value is not None:
+                target, value = node.target, node.value
+            else:
+                if isinstance(node, ast.Subscript) and isinstance(node.ctx, ast.Store):
+                    key = self._binding_key(node.value)
+                    if key is not None and isinstance(node.slice, ast.Tuple):
+                        shapes.setdefault(key, set()).add("tuple")
+                continue
+            if isinstance(value, (ast.Dict, ast.DictComp)):
+                keys = [value.key] if isinstance(value, ast.DictComp) else [k for k in value.keys if k is not None]
+            elif isinstance(value, (ast.Set, ast.SetComp)):
+                keys = [value.elt] if isinstance(value, ast.SetComp) else value.elts
+            else:
+                continue
+            key = self._binding_key(target)
+            if key is None:
+                continue
+            literal_targets.add(target)
+            lines.setdefault(key, node.lineno)
+            shapes.setdefault(key, set()).update("tuple" if isinstance(k, ast.Tuple) else "scalar" for k in keys)
+
+        for node in ast.walk(self.tree):
+            if isinstance(node, (ast.Name, ast.Attribute)) and isinstance(node.ctx, ast.Store):
+                if node not in literal_targets:
+                    lines.pop(self._binding_key(node), None)
+            elif isinstance(node, ast.arg) and node in self.scope_of:
+                lines.pop((self.scope_of[node], node.arg), None)
+
+        return {
+            key: (next(iter(shapes[key])), line)
+            for key, line in lines.items()
+            if len(shapes.get(key, ())) == 1
+        }
+
+    def _key_kind(self, node: Optional[ast.expr]) -> Optional[Tuple[str, str, int]]:
+        """(name, "tuple" | "scalar", line) for a tracked dict/set, else None."""
+        key = self._binding_key(node) if node is not None else None
+        if key not in self.key_kinds:
+            return None
+        kind, line = self.key_kinds[key]
+        return key[1], kind, line
+
+    # ------------------------------------------------------------------
+    # Signatures
+    # ------------------------------------------------------------------
+
+    def _collect_signatures(self) -> Dict[str, Optional[Signature]]:
+        """
+        Signatures of callables defined in the module: functions and classes
+        by name (classes through `__init__`, or their fields for `@dataclass`
+        and `NamedTuple`), methods by `Class.name` as called on an instance.
+        Static and class methods are also noted in `class_level_methods`.
+        Names defined more than once map to None.
+        """
+        signatures: Dict[str, Optional[Signature]] = {}
+
+        def record(key: str, signature: Signature) -> None:
+            signatures[key] = None if key in signatures else signature
+
+        methods: Set[ast.AST] = set()
+        for node in ast.walk(self.tree):
+            if not isinstance(node, ast.ClassDef):
+                continue
+            for stmt in node.body:
+                if isinstance(stmt, (ast.FunctionDef, ast.AsyncFunctionDef)):
+                    methods.add(stmt)
+                    key = f"{node.name}.{stmt.name}"
+                    record(key, self._function_signature(stmt, method=True))
+                    decorators = {self._dotted_name(d) for d in stmt.decorator_list}
+                    if decorators & {"staticmethod", "classmethod"}:
+                        self.class_level_methods.add(key)
+            init = next((s for s in node.body if isinstance(s, ast.FunctionDef) and s.name == "__init__"), None)
+            if self._is_field_class(node):
+                fields = [
+                    (stmt.target.id, stmt.annotation)
+                    for stmt in node.body
+                    if isinstance(stmt, ast.AnnAssign) and isinstance(stmt.target, ast.Name)
+                    and not self._is_classvar(stmt.annotation)
+                ]
+                record(node.name, (fields, dict(fields)))
+            elif init is not None:
+                record(node.name, self._function_signature(init, method=True))
+
+        for node in ast.walk(self.tree):
+            if isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef)) and node not in methods:
+                record(node.name, self._function_signature(node, method=False))
+        return signatures
+
+    def _collect_instances(self) -> Dict[BindingKey, str]:
+        """Names bound only to `C(...)` for a class `C` defined in the module: `mailer = Mailer()`."""
+        classes = {node.name for node in ast.walk(self.tree) if isinstance(node, ast.ClassDef)}
+        instances: Dict[BindingKey, Optional[str]] = {}
+        constructed: Set[ast.AST] = set()
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Assign) and len(node.targets) == 1:
+                target, value = node.targets[0], node.value
+            elif isinstance(node, ast.AnnAssign) and node.value is not None:
+                target, value = node.target, node.value
+            else:
+                continue
+            if not (isinstance(value, ast.Call) and isinstance(value.func, ast.Name) and value.func.id in classes):
+                continue
+            key = self._binding_key(target)
+            if key is not None:
+                constructed.add(target)
+                instances[key] = value.func.id if instances.get(key, value.func.id) == value.func.id else None
+
+        for node in ast.walk(self.tree):
+            if isinstance(node, (ast.Name, ast.Attribute)) and isinstance(node.ctx, ast.Store) and node not in constructed:
+                key = self._binding_key(node)
+                if key in instances:
+                    instances[key] = None
+        return {key: cls for key, cls in instances.items() if cls is not None}
+
+    def _method_key(self, func: ast.Attribute) -> Optional[str]:
+        """
+        `Class.method` for a call on `self` / `cls`, on the class itself (static
+        and class methods only), or on a name bound to the class's constructor.
+        Unknown receivers such as `sock.send` resolve to None.
+        """
+        receiver = func.value
+        if isinstance(receiver, ast.Name) and receiver.id in ("self", "cls"):
+            owner = self._enclosing_class(self.scope_of.get(receiver, self.tree))
+            return None if owner is None else f"{owner.name}.{func.attr}"
+        if isinstance(receiver, ast.Name) and f"{receiver.id}.{func.attr}" in self.class_level_methods:
+            return f"{receiver.id}.{func.attr}"
+        key = self._binding_key(receiver)
+        if key is None or key not in self.instances:
+            return None
+        return f"{self.instances[key]}.{func.attr}"
+
+    def _function_signature(self, node: Union[ast.FunctionDef, ast.AsyncFunctionDef], method: bool) -> Signature:
+        args = node.args
+        positional = args.posonlyargs + args.args
+        static = any(self._dotted_name(d) == "staticmethod" for d in node.decorator_list)
+        if method and not static and positional:
+            positional = positional[1:]  # self / cls
+        keyword = [a for a in positional if a not in args.posonlyargs] + args.kwonlyargs
+        return [(a.arg, a.annotation) for a in positional], {a.arg: a.annotation for a in keyword}
+
+    def _is_field_class(self, node: ast.ClassDef) -> bool:
+        """`@dataclass` / `@dataclass(...)` classes and `NamedTuple` subclasses."""
+        for decorator in node.decorator_list:
+            target = decorator.func if isinstance(decorator, ast.Call) else decorator
+            if self._qualified_name(target) == "dataclasses.dataclass":
+                return True
+        return any(self._qualified_name(base) == "typing.NamedTuple" for base in node.bases)
+
+    def _is_classvar(self, annotation: ast.expr) -> bool:
+        if isinstance(annotation, ast.Subscript):
+            annotation = annotation.value
+        name = self._dotted_name(annotation)
+        return name is not None and name.rsplit(".", 1)[-1] == "ClassVar"
+
+    # ------------------------------------------------------------------
+    # Project mode
+    # ------------------------------------------------------------------
+
+    def _load_project_index(self) -> Optional[ProjectIndex]:
+        if not self.project_root:
+            return None
+        index = ProjectIndex.for_root(self.project_root, self.module_string_bindings)
+        module = index.module_name(self.filename)
+        if module is None:
+            return None
+        self.imported_names = self._collect_imported_names(index, module)
+        return index
+
+    def _collect_imported_names(self, index: ProjectIndex, module: str) -> Dict[str, str]:
+        """
+        Map each name this module imports to the fully qualified name it
+        refers to, resolving relative imports against `module`. Names that
+        are also assigned locally are dropped — the import is shadowed.
+        """
+        imported: Dict[str, str] = {}
+        assigned = set()
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Import):
+                for alias in node.names:
+                    if alias.asname:
+                        imported[alias.asname] = alias.name
+                    else:
+                        head = alias.name.split(".")[0]
+                        imported[head] = head
+            elif isinstance(node, ast.ImportFrom):
+                source = index.absolute_module(module, self.filename, node.level, node.module)
+                if source is None:
+                    continue
+                for alias in node.names:
+                    imported[alias.asname or alias.name] = f"{source}.{alias.name}"
+            elif isinstance(node, ast.Name) and isinstance(node.ctx, ast.Store):
+                assigned.add(node.id)
+            elif isinstance(node, ast.arg):
+                assigned.add(node.arg)
+        return {local: target for local, target in imported.items() if local not in assigned}
+
+    def _resolve_imported_binding(self, dotted: str) -> Optional[Tuple[str, int]]:
+        """`ALLOWED` or `constants.ALLOWED` -> (defining path, line) via the index."""
+        if self.project_index is None:
+            return None
+        parts = dotted.split(".")
+        for i in range(len(parts), 0, -1):
+            head = ".".join(parts[:i])
+            if head in self.imported_names:
+                qualified = ".".join([self.imported_names[head]] + parts[i:])
+                return self.project_index.resolve(qualified)
+        return None
+
+    def _binding_message(self, value: ast.expr) -> str:
+        if value not in self.used_bindings:
+            return self.STC001
+        name, line = self.used_bindings[value]
+        return self.STC001_USED.format(name=name, line=line)
+
+    # ------------------------------------------------------------------
+    # Rules
+    # ------------------------------------------------------------------
+
+    def _check_iterable_consumer(self, node: ast.Call) -> None:
+        qualname = self._qualified_name(node.func)
+        if qualname not in self.ITERABLE_CONSUMERS:
+            return
+        # Bare builtins only count when the module doesn't rebind them.
+        if "." not in qualname and qualname in self.bound_names:
+            return
+        positions, keywords = self.ITERABLE_CONSUMERS[qualname]
+        message = self.STC003.format(qualname)
+        for arg in self._call_arguments(node, positions, keywords):
+            if self._is_string_literal(arg):
+                self._check_call_argument(node, arg, message)
+
+    def _check_collection_mutation(self, node: ast.Call) -> None:
+        func = node.func
+        if not isinstance(func, ast.Attribute) or func.attr not in self.MUTATING_METHODS:
+            return
+        position = 1 if self._qualified_name(func.value) in self.MUTATING_TYPES else 0
+        if len(node.args) <= position or any(isinstance(a, ast.Starred) for a in node.args[:position + 1]):
+            return
+        arg = node.args[position]
+        if self._is_string_literal(arg):
+            self._check_call_argument(node, arg, self._mutation_message(arg))
+
+    def _check_container_constructor(self, node: ast.Call) -> None:
+        """
+        `tuple("foo")`, `set("admin")` and friends: a container built from a
+        single bare string literal. There are no extra parentheses here, so
+        this is an AST-only check rather than a token-span one.
+        """
+        func = node.func
+        if not isinstance(func, ast.Name) or func.id not in self.CONTAINER_CONSTRUCTORS:
+            return
+        if func.id in self.bound_names or node.keywords or len(node.args) != 1:
+            return
+        arg = node.args[0]
+        if isinstance(arg, ast.Constant) and isinstance(arg.value, (str, bytes)):
+            # `frozenset("0123456789abcdef")` is a deliberate character set;
+            # only words such as "admin" read as a missed one-tuple.
+            text = arg.value.decode("latin-1") if isinstance(arg.value, bytes) else arg.value
+            if not _WORD.fullmatch(text) or self._looks_like_charset(text):
+                return
+        elif not isinstance(arg, ast.JoinedStr):
+            return
+        # `set(("admin"))` already gets STC003 at the inner parenthesis.
+        violation_idx = self._find_candidate_violation(arg, in_membership=False)
+        if violation_idx is not None and violation_idx != self._call_open_paren_idx(node):
+            return
+        literal = self._source_segment(arg)
+        suggestion = self.CONTAINER_CONSTRUCTORS[func.id].format(literal)
+        self._report_node(node, self.STC005.format(call=f"{func.id}({literal})", suggestion=suggestion))
+
+    @classmethod
+    def _looks_like_charset(cls, text: str) -> bool:
+        """A well-known character set such as `"aeiou"`, or a run like `"abcd"` / `"wxyz"`."""
+        if text in cls.KNOWN_CHARSETS:
+            return True
+        run = 1
+        for a, b in zip(text, text[1:]):
+            run = run + 1 if ord(b) == ord(a) + 1 else 1
+            if run >= 4:
+                return True
+        return False
+
+    def _check_printf_operand(self, node: ast.BinOp) -> None:
+        """
+        `"value: %s" % (value)` — the parens suggest a one-tuple was meant, but
+        the operand is formatted as-is and breaks when it is itself a tuple.
+        Mapping-key formats (`%(name)s`) take a dict and are skipped.
+        """
+        if not (isinstance(node.left, ast.Constant) and isinstance(node.left.value, (str, bytes))):
+            return
+        fmt = node.left.value if isinstance(node.left.value, str) else node.left.value.decode("latin-1")
+        count = 0
+        for match in _PRINTF_DIRECTIVE.finditer(fmt):
+            if match.group("conv") == "%":
+                continue
+            if match.group("key") is not None:
+                return
+            count += 1 + (match.group("width") == "*") + (match.group("precision") == "*")
+        if count == 0:
+            return
+        directives = f"{count} directive{'' if count == 1 else 's'}"
+        self._check_candidate(node.right, in_membership=False, message=self.STC007.format(directives))
+
+    def _check_annotated_function(self, node: Union[ast.FunctionDef, ast.AsyncFunctionDef]) -> None:
+        """
+        Parameter defaults against parameter annotations, then `return` values
+        against the return annotation — or, for generators, each `yield`
+        against the element type of `Iterator[...]` / `Generator[...]`.
+        """
+        args = node.args
+        positional = args.posonlyargs + args.args
+        for arg, default in zip(positional[len(positional) - len(args.defaults):], args.defaults):
+            self._check_annotated_value(arg.annotation, default)
+        for arg, default in zip(args.kwonlyargs, args.kw_defaults):
+            self._check_annotated_value(arg.annotation, default)
+
+        if node.returns is None:
+            return
+        body = list(self._own_nodes(node))
+        if any(isinstance(n, (ast.Yield, ast.YieldFrom)) for n in body):
+            element = self._iterator_element_annotation(node.returns)
+            for n in body:
+                if isinstance(n, ast.Yield):
+                    self._check_annotated_value(element, n.value)
+        else:
+            intended = self._is_sequence_annotation(self._parse_string_annotation(node.returns))
+            for n in body:
+                if isinstance(n, ast.Return):
+                    self._check_annotated_value(node.returns, n.value)
+                    if intended:
+                        # `return x,` under `-> tuple[int]` is the declared one-tuple.
+                        self.tuple_returns.add(n)
+
+    @staticmethod
+    def _own_nodes(func: ast.AST) -> Generator[ast.AST, None, None]:
+        """Walk a function body without descending into nested scopes."""
+        stack = list(ast.iter_child_nodes(func))
+        while stack:
+            node = stack.pop()
+            yield node
+            if not isinstance(node, (ast.FunctionDef, ast.AsyncFunctionDef, ast.Lambda, ast.ClassDef)):
+                stack.extend(ast.iter_child_nodes(node))
+
+    def _check_annotated_value(self, annotation: Optional[ast.expr], value: Optional[ast.expr]) -> None:
+        if annotation is None or value is None or isinstance(value, ast.Tuple):
+            return
+        # `(None)` is what `Optional[...]` allows, and never a missed comma.
+        if self._is_none(value):
+            return
+        # Calls, attributes and subscripts usually produce the declared type;
+        # parens around them are line wrapping, not a missed comma.
+        if isinstance(value, (ast.Call, ast.Attribute, ast.Subscript)):
+            return
+        annotation = self._parse_string_annotation(annotation)
+        if not self._is_sequence_annotation(annotation):
+            return
+        # `(["a"])` or `(set(names))` is a sequence already — odd, but not this bug.
+        if self._infer_kind(value) == "container":
+            return
+        open_idx = self._find_candidate_violation(value, in_membership=False)
+        if open_idx is None or self._parens_span_lines(open_idx):
+            return
+        # Unparsed rather than sliced from source: it may come from a string annotation.
+        label = ast.unparse(annotation)
+        # `tuple[str, int]` can't be satisfied by a one-tuple either, so no `(x,)` there.
+        arity = self._tuple_arity(annotation)
+        if arity is None or arity == 1:
+            message = self.STC019.format(label)
+        else:
+            message = self.STC019_ARITY.format(label, arity)
+        self._check_candidate(value, in_membership=False, message=message)
+
+    def _parse_string_annotation(self, annotation: ast.expr) -> ast.expr:
+        """`"tuple[str, ...]"` forward references are parsed; anything else passes through."""
+        if isinstance(annotation, ast.Constant) and isinstance(annotation.value, str):
+            try:
+                return ast.parse(annotation.value, mode="eval").body
+            except SyntaxError:
+                return annotation
+        return annotation
+
+    def _unwrap_optional(self, annotation: ast.expr) -> ast.expr:
+        """`Optional[X]` and `X | None` -> `X`."""
+        if isinstance(annotation, ast.Subscript):
+            base = self._dotted_name(annotation.value)
+            if base is not None and base.rsplit(".", 1)[-1] == "Optional":
+                return annotation.slice
+        elif isinstance(annotation, ast.BinOp) and isinstance(annotation.op, ast.BitOr):
+            sides = [a for a in (annotation.left, annotation.right) if not self._is_none(a)]
+            if len(sides) == 1:
+                return sides[0]
+        return annotation
+
+    def _is_sequence_annotation(self, annotation: Optional[ast.expr]) -> bool:
+        if annotation is None:
+            return False
+        annotation = self._unwrap_optional(annotation)
+        if isinstance(annotation, ast.Subscript):
+            annotation = annotation.value
+        name = self._dotted_name(annotation)
+        return name is not None and name.rsplit(".", 1)[-1] in self.SEQUENCE_ANNOTATIONS
+
+    def _tuple_arity(self, annotation: ast.expr) -> Optional[int]:
+        """`tuple[int, int]` -> 2, `Tuple[str]` -> 1; None for `tuple[int, ...]` and non-tuples."""
+        annotation = self._unwrap_optional(annotation)
+        if not isinstance(annotation, ast.Subscript):
+            return None
+        name = self._dotted_name(annotation.value)
+        if name is None or name.rsplit(".", 1)[-1] not in ("tuple", "Tuple"):
+            return None
+        if not isinstance(annotation.slice, ast.Tuple):
+            return 1
+        elts = annotation.slice.elts
+        if any(isinstance(e, ast.Constant) and e.value is Ellipsis for e in elts):
+            return None
+        return len(elts)
+
+    def _iterator_element_annotation(self, annotation: ast.expr) -> Optional[ast.expr]:
+        """`Iterator[tuple[str, int]]` -> `tuple[str, int]`; None when not an iterator type."""
+        annotation = self._unwrap_optional(self._parse_string_annotation(annotation))
+        if not isinstance(annotation, ast.Subscript):
+            return None
+        name = self._dotted_name(annotation.value)
+        if name is None or name.rsplit(".", 1)[-1] not in self.ITERATOR_ANNOTATIONS:
+            return None
+        element = annotation.slice
+        if isinstance(element, ast.Tuple):  # Generator[Yield, Send, Return]
+            element = element.elts[0] if element.elts else None
+        return element
+
+    def _check_signature_arguments(self, node: ast.Call) -> None:
+        """
+        `notify(("email"))` where the module declares
+        `def notify(channels: Iterable[str])` — a parenthesized string bound
+        to a parameter annotated as a container.
+        """
+        func = node.func
+        if isinstance(func, ast.Name):
+            key: Optional[str] = func.id
+        elif isinstance(func, ast.Attribute):
+            key = self._method_key(func)
+        else:
+            return
+        signature = self.signatures.get(key) if key is not None else None
+        if signature is None:
+            return
+        positional, keywords = signature
+        bound: List[Tuple[str, Optional[ast.expr], ast.expr]] = []
+        for (param, annotation), arg in zip(positional, node.args):
+            if isinstance(arg, ast.Starred):
+                break
+            bound.append((param, annotation, arg))
+        for kw in node.keywords:
+            if kw.arg in keywords:
+                bound.append((kw.arg, keywords[kw.arg], kw.value))
+
+        callee = self._dotted_name(func) or key
+        for param, annotation, arg in bound:
+            if annotation is None or not self._is_string_literal(arg):
+                continue
+            annotation = self._parse_string_annotation(annotation)
+            if not self._is_parameter_sequence(annotation):
+                continue
+            message = self.STC020.format(callee=callee, param=param, annotation=ast.unparse(annotation))
+            self._check_call_argument(node, arg, message)
+
+    def _is_parameter_sequence(self, annotation: ast.expr) -> bool:
+        annotation = self._unwrap_optional(annotation)
+        if isinstance(annotation, ast.Subscript):
+            annotation = annotation.value
+        name = self._dotted_name(annotation)
+        return name is not None and name.rsplit(".", 1)[-1] in self.PARAMETER_SEQUENCE_ANNOTATIONS
+
+    def _dunder_message(self, target: ast.expr) -> Optional[str]:
+        if isinstance(target, ast.Name):
+            return self.DUNDER_SEQUENCES.get(target.id)
+        return None
+
+    def _check_dunder_sequence(self, target: ast.expr, value: ast.expr) -> None:
+        """
+        `__all__ = ("public_fn")`, `__slots__ = ("name")`,
+        `__match_args__ = ("x")` and `__all__ += ("helper")`: reported for any
+        parenthesized non-tuple, not just strings; the string consequence is
+        only claimed for strings. Runs before the generic rules so it claims
+        the node.
+        """
+        message = self._dunder_message(target)
+        if message is None or self._infer_kind(value) == "container":
+            return
+        self._check_candidate(value, in_membership=False, message=self._pack_message(message, value))
+
+    def _check_dbapi_parameters(self, node: ast.Call) -> None:
+        """
+        `cursor.execute("... WHERE id = ?", (user_id))` on any receiver. Only
+        literal SQL with positional placeholders is considered; named styles
+        take a mapping, where parentheses around a name are harmless.
+        """
+        func = node.func
+        if not isinstance(func, ast.Attribute) or func.attr not in self.DBAPI_METHODS:
+            return
+        if len(node.args) < 2 or any(isinstance(a, ast.Starred) for a in node.args[:2]):
+            return
+        sql, params = node.args[0], node.args[1]
+        if not (isinstance(sql, ast.Constant) and isinstance(sql.value, str)):
+            return
+        statement = _SQL_QUOTED.sub("", sql.value)
+        count = len(_SQL_POSITIONAL.findall(statement))
+        if count == 0 or _SQL_NAMED.search(statement):
+            return
+        placeholders = f"{count} placeholder{'' if count == 1 else 's'}"
+
+        if func.attr == "executemany":
+            rows = params.elts if isinstance(params, (ast.List, ast.Tuple)) else []
+            for row in rows:
+                self._check_dbapi_row(node, row, func.attr, placeholders)
+        else:
+            self._check_dbapi_row(node, params, func.attr, placeholders)
+
+    def _check_dbapi_row(self, call: ast.Call, row: ast.expr, method: str, placeholders: str) -> None:
+        if isinstance(row, ast.Tuple) or self._infer_kind(row) == "container":
+            return
+        suggestion = f"({self._source_segment(row)},)"
+        message = self.STC024.format(method=method, placeholders=placeholders, suggestion=suggestion)
+        self._check_call_argument(call, row, message)
+
+    def _check_membership_lhs(self, node: ast.Compare) -> None:
+        """
+        The LHS of `(key) in d` depends on what `d` holds: against tuple keys
+        a parenthesized scalar is STC018, against scalar keys the parens are
+        harmless, otherwise the usual STC001 rules apply.
+        """
+        container = node.comparators[0] if isinstance(node.ops[0], (ast.In, ast.NotIn)) else None
+        keys = self._key_kind(container)
+        if keys is None:
+            self._check_membership_side(node.left)
+        elif keys[1] == "tuple":
+            self._check_candidate(node.left, in_membership=False, message=self._tuple_key_message(keys))
+
+    def _check_tuple_key(self, container: ast.expr, key: ast.expr) -> None:
+        keys = self._key_kind(container)
+        if keys is not None and keys[1] == "tuple" and not isinstance(key, ast.Tuple):
+            self._check_candidate(key, in_membership=False, message=self._tuple_key_message(keys))
+
+    def _check_key_method(self, node: ast.Call) -> None:
+        """`d.get(("a"))`, `d.pop(("a"))`, `d.setdefault(("a"), ...)` on tuple-keyed dicts."""
+        func = node.func
+        if not isinstance(func, ast.Attribute) or func.attr not in self.KEY_METHODS or not node.args:
+            return
+        keys = self._key_kind(func.value)
+        key = node.args[0]
+        if keys is not None and keys[1] == "tuple" and not isinstance(key, (ast.Tuple, ast.Starred)):
+            self._check_call_argument(node, key, self._tuple_key_message(keys))
+
+    def _tuple_key_message(self, keys: Tuple[str, str, int]) -> str:
+        name, _, line = keys
+        return self.STC018.format(name=name, line=line)
+
+    def _check_membership_side(self, node: ast.expr) -> None:
+        """
+        STC001 for one side of `in` / `not in`, unless intra-module inference
+        shows the parenthesized value is a non-string container — then
+        `key in (self.cache)` is harmless grouping. Strings and unknowns are
+        still reported.
+        """
+        if self._infer_kind(node) == "container":
+            return
+        self._check_candidate(node, in_membership=True)
+
+    def _check_implicit_join(self, node: ast.expr, context: str, same_line_only: bool = False) -> None:
+        """
+        `x in ("foo" "bar")` is a substring search in "foobar", not a
+        membership test. Reported at the literal that is missing its comma.
+        `same_line_only` keeps long strings wrapped across lines exempt.
+        """
+        is_bytes = isinstance(node, ast.Constant) and isinstance(node.value, bytes)
+        if not (is_bytes or self._is_string_literal(node)):
+            return
+        literals = self._string_literal_tokens(node)
+        for prev, tok in zip(literals, literals[1:]):
+            if same_line_only and prev.end[0] != tok.start[0]:
+                continue
+            self.violations.append((tok.start[0], tok.start[1], self.STC008.format(context), type(self)))
+            return
+
+    def _check_collection_elements(self, node: ast.expr) -> None:
+        if isinstance(node, (ast.List, ast.Tuple)) and not isinstance(node.ctx, ast.Load):
+            return
+        missed = self._missed_comma_element(node.elts)
+        for elt in node.elts:
+            self._check_implicit_join(elt, "collection literal", same_line_only=elt is not missed)
+        self._check_shape_outliers(node.elts, self.STC012)
+
+    def _missed_comma_element(self, elts: List[ast.expr]) -> Optional[ast.expr]:
+        """
+        Joins wrapped across lines are the usual long-string idiom and only
+        same-line joins are reported — except in a one-string-per-line
+        literal, where `"staff"` / `"guest"` on their own lines are two
+        elements missing a comma. That needs every other element to be a
+        single string on its own line, and the join's fragments to sit on
+        separate lines, outside parentheses of their own.
+        """
+        missed = None
+        previous_end = 0
+        for elt in elts:
+            if not self._is_string_literal(elt) or elt.lineno <= previous_end:
+                return None
+            previous_end = elt.end_lineno
+            literals = self._string_literal_tokens(elt)
+            if len(literals) == 1 and elt.lineno == elt.end_lineno:
+                continue
+            lines = [tok.start[0] for tok in literals]
+            if missed is not None or len(set(lines)) != len(lines) or self._is_parenthesized(elt):
+                return None
+            missed = elt
+        return missed if len(elts) > 1 else None
+
+    def _is_parenthesized(self, node: ast.expr) -> bool:
+        span = self._node_token_span(node)
+        if span is None:
+            return False
+        before, open_idx = self._next_meaningful(span[0], -1)
+        after, close_idx = self._next_meaningful(span[1], 1)
+        if before is None or after is None or before.string != "(":
+            return False
+        return self._find_matching_paren(open_idx) == close_idx
+
+    def _check_dict_call_values(self, node: ast.Call) -> None:
+        """`dict(read=("GET", "HEAD"), write=("POST"))` — same as a dict literal."""
+        func = node.func
+        if not isinstance(func, ast.Name) or func.id != "dict" or func.id in self.bound_names:
+            return
+        values = [kw.value for kw in node.keywords if kw.arg is not None]
+        self._check_shape_outliers(values, self.STC013)
+
+    def _check_call_site_shapes(self) -> None:
+        """
+        Module-wide: for every callee called at least three times, line up
+        each positional slot and keyword across its call sites. When a clear
+        majority (at least two, and more than the rest) pass a tuple literal,
+        parenthesized non-tuples in the same slot are reported with a pointer
+        to one of the majority call sites. Runs after the visit.
+        """
+        for callee, calls in self.call_sites.items():
+            # Two tuples plus an outlier is the smallest case that can report.
+            if len(calls) < 3:
+                continue
+            slots: Dict[Union[int, str], List[Tuple[ast.Call, ast.expr]]] = {}
+            for call in calls:
+                for i, arg in enumerate(call.args):
+                    if isinstance(arg, ast.Starred):
+                        break
+                    slots.setdefault(i, []).append((call, arg))
+                for kw in call.keywords:
+                    if kw.arg is not None:
+                        slots.setdefault(kw.arg, []).append((call, kw.value))
+
+            for entries in slots.values():
+                tuples = [(c, v) for c, v in entries if isinstance(v, ast.Tuple)]
+                others = [(c, v) for c, v in entries if not isinstance(v, ast.Tuple)]
+                if len(tuples) < 2 or len(tuples) <= len(others):
+                    continue
+                arity = Counter(len(v.elts) for _, v in tuples).most_common(1)[0][0]
+                line = next(v.lineno for _, v in tuples if len(v.elts) == arity)
+                message = self.STC016.format(arity=arity, callee=callee, line=line)
+                for call, value in others:
+                    self._check_call_argument(call, value, message)
+
+    def _check_class_body_shapes(self, node: ast.ClassDef) -> None:
+        """
+        `class Perm(Enum): READ = ("r", 4); WRITE = ("w")` — compare the
+        values assigned to plain names directly in the class body. Enum
+        members are expected to be uniform; ordinary classes need a clear
+        tuple majority (at least two, and more than the non-tuples).
+        NamedTuple fields differ by design and are left to their annotations
+        (STC019).
+        """
+        if any(self._qualified_name(base) == "typing.NamedTuple" for base in node.bases):
+            return
+        values: List[ast.expr] = []
+        for stmt in node.body:
+            if isinstance(stmt, ast.Assign) and len(stmt.targets) == 1:
+                target = stmt.targets[0]
+            elif isinstance(stmt, ast.AnnAssign) and stmt.value is not None:
+                target = stmt.target
+            else:
+                continue
+            if isinstance(target, ast.Name) and not self._is_dunder(target.id):
+                values.append(stmt.value)
+
+        uniform = any(self._qualified_name(base) in self.UNIFORM_CLASS_BASES for base in node.bases)
+        if not uniform:
+            tuples = sum(isinstance(v, ast.Tuple) for v in values)
+            if tuples < 2 or tuples <= len(values) - tuples:
+                return
+        self._check_shape_outliers(values, self.STC014)
+
+    def _check_case_pattern_shapes(self, node: "ast.Match") -> None:
+        """
+        `case ("a", "b"): ... case ("c"): ...` — the second pattern is a group
+        around a value pattern, not a one-element sequence pattern. Flagged
+        when sequence patterns are at least as common as such groups. A
+        grouped capture `case (x):` matches anything; the wildcard `case (_):`
+        is a deliberate catch-all and is left alone.
+        """
+        sequences: List[ast.AST] = []
+        groups: List[Tuple[ast.AST, int]] = []
+        for case in node.cases:
+            pattern = case.pattern
+            if isinstance(pattern, ast.MatchSequence):
+                sequences.append(pattern)
+            elif isinstance(pattern, ast.MatchAs) and pattern.name is None:
+                continue
+            elif isinstance(pattern, (ast.MatchValue, ast.MatchAs)):
+                span = self._node_token_span(pattern)
+                violation_idx = self._check_violation(*span) if span is not None else None
+                if violation_idx is not None:
+                    groups.append((pattern, violation_idx))
+        if not sequences or not groups or len(sequences) < len(groups):
+            return
+        arity = Counter(len(p.patterns) for p in sequences).most_common(1)[0][0]
+        for pattern, violation_idx in groups:
+            capture = isinstance(pattern, ast.MatchAs) and pattern.pattern is None
+            self.reported_nodes.add(pattern)
+            self._report(violation_idx, (self.STC015_CAPTURE if capture else self.STC015).format(arity))
+
+    @staticmethod
+    def _is_dunder(name: str) -> bool:
+        return name.startswith("__") and name.endswith("__")
+
+    def _check_shape_outliers(self, values: List[ast.expr], message: str) -> None:
+        """
+        Shape consistency: when at least half of `values` are tuple literals,
+        a parenthesized non-tuple among them is most likely a missed comma.
+        `message` is formatted with the most common sibling arity.
+        """
+        values = [v for v in values if not isinstance(v, ast.Starred)]
+        tuples = [v for v in values if isinstance(v, ast.Tuple)]
+        others = [v for v in values if not isinstance(v, ast.Tuple)]
+        if not tuples or not others or len(tuples) < len(others):
+            return
+        arity = Counter(len(t.elts) for t in tuples).most_common(1)[0][0]
+        for value in others:
+            self._check_candidate(value, in_membership=False, message=message.format(arity))
+
+    def _check_trailing_comma(self, node: Optional[ast.expr], message: str) -> None:
+        """
+        `timeout = 30,` — a one-element tuple whose last token is the comma
+        itself, i.e. not wrapped in parentheses. `(30,)` is left alone as an
+        explicit tuple.
+        """
+        if not isinstance(node, ast.Tuple) or len(node.elts) != 1:
+            return
+        # `x = *items,` builds a tuple from an iterable; the comma is required.
+        if isinstance(node.elts[0], ast.Starred):
+            return
+        span = self._node_token_span(node)
+        if span is None:
+            return
+        end_idx = span[1]
+        if self.tokens[end_idx].string == ",":
+            self._report(end_idx, message)
+
+    def _destructuring_message(self, target: ast.expr, value: ast.expr) -> str:
+        """
+        Describe the arity of `a, b = ("xy")`: how many targets there are and,
+        for a plain string constant, how many characters will be unpacked.
+        """
+        starred = any(isinstance(elt, ast.Starred) for elt in target.elts)
+        count = len(target.elts) - starred
+        targets = f"{'at least ' if starred else ''}{count} target{'' if count == 1 else 's'}"
+        if not isinstance(value, ast.Constant):
+            return self.STC006.format(targets)
+        length = len(value.value)
+        return self.STC006.format(f"{targets}, {length} character{'' if length == 1 else 's'}")
+
+    def _mutation_message(self, node: ast.expr) -> str:
+        if isinstance(node, ast.Constant):
+            count = len(node.value)
+            return self.STC004.format(f"{count} character{'' if count == 1 else 's'}")
+        return self.STC004.format("each character")
+
+    # ------------------------------------------------------------------
+    # Rule packs
+    # ------------------------------------------------------------------
+
+    @staticmethod
+    def _simple_assignments(body: List[ast.stmt]) -> Generator[Tuple[str, ast.expr], None, None]:
+        """(name, value) for each `name = value` / `name: T = value` statement in `body`."""
+        for stmt in body:
+            if isinstance(stmt, ast.Assign):
+                for target in stmt.targets:
+                    if isinstance(target, ast.Name):
+                        yield target.id, stmt.value
+            elif isinstance(stmt, ast.AnnAssign) and stmt.value is not None and isinstance(stmt.target, ast.Name):
+                yield stmt.target.id, stmt.value
+
+    def _base_names(self, node: ast.ClassDef) -> Set[str]:
+        names = set()
+        for base in node.bases:
+            qualname = self._qualified_name(base)
+            if qualname is not None:
+                names.add(qualname.rsplit(".", 1)[-1])
+        return names
+
+    def _check_pack_value(self, value: ast.expr, message: str) -> None:
+        """Any parenthesized non-tuple, unless it is known to be a container already."""
+        if isinstance(value, ast.Tuple) or self._infer_kind(value) == "container":
+            return
+        self._check_candidate(value, in_membership=False, message=self._pack_message(message, value))
+
+    def _pack_message(self, message: str, value: ast.expr) -> str:
+        """
+        Pack and dunder messages explain what happens to a string ("..., so Django
+        iterates it character by character; ..."). For any other value that
+        clause may be false — `(FIELDS)` can be a list — so it is dropped.
+        """
+        if self._is_string_literal(value):
+            return message
+        head, sep, _ = message.partition(", so ")
+        return f"{head}; did you mean `(x,)`?" if sep else message
+
+    def _is_django_settings(self, node: ast.Module) -> bool:
+        """`settings.py`, any module in a `settings/` package, or one defining INSTALLED_APPS."""
+        path = os.path.normpath(self.filename)
+        if os.path.basename(path) == "settings.py" or os.path.basename(os.path.dirname(path)) == "settings":
+            return True
+        return any(name == "INSTALLED_APPS" for name, _ in self._simple_assignments(node.body))
+
+    def _check_django_class(self, node: ast.ClassDef) -> None:
+        """
+        `class Meta`, ModelAdmin/inline and DRF view bodies. `unique_together`
+        and `permissions` also catch `(("a", "b"))`, which reads like a group
+        of one group. `fields = ("__all__")` is the documented string value.
+        """
+        bases = self._base_names(node)
+        attributes: Set[str] = set()
+        if node.name == "Meta":
+            attributes |= self.DJANGO_META_ATTRIBUTES
+        if bases & self.DJANGO_ADMIN_BASES:
+            attributes |= self.DJANGO_ADMIN_ATTRIBUTES
+        if bases & self.DJANGO_VIEW_BASES:
+            attributes |= self.DJANGO_VIEW_ATTRIBUTES
+        for name, value in self._simple_assignments(node.body):
+            if name not in attributes:
+                continue
+            if name in self.DJANGO_ALL_ATTRIBUTES and isinstance(value, ast.Constant) and value.value == "__all__":
+                # A valid setting; keep STC001 off its parentheses too.
+                self.reported_nodes.add(value)
+                continue
+            if isinstance(value, ast.Tuple) and name in self.DJANGO_GROUPED_ATTRIBUTES:
+                if value.elts and all(isinstance(e, ast.Constant) for e in value.elts):
+                    self._check_candidate(value, in_membership=False, message=self.STC102.format(name))
+            else:
+                self._check_pack_value(value, self.STC101.format(name))
+
+    def _check_pytest_call(self, node: ast.Call) -> None:
+        qualname = self._qualified_name(node.func)
+        if qualname == "pytest.fixture":
+            for kw in node.keywords:
+                if kw.arg == "params":
+                    self._check_pack_argument(node, kw.value, self.STC202)
+        elif qualname == "pytest.mark.parametrize":
+            argnames = self._positional_or_keyword(node, 0, "argnames")
+            argvalues = self._positional_or_keyword(node, 1, "argvalues")
+            if argvalues is None:
+                return
+            self._check_pack_argument(node, argvalues, self.STC201)
+            names = self._pytest_argnames(argnames)
+            if len(names) > 1 and isinstance(argvalues, (ast.List, ast.Tuple)):
+                for row in argvalues.elts:
+                    self._check_parametrize_row(row, len(names))
+
+    @staticmethod
+    def _positional_or_keyword(node: ast.Call, position: int, keyword: str) -> Optional[ast.expr]:
+        if len(node.args) > position and not any(isinstance(a, ast.Starred) for a in node.args[:position + 1]):
+            return node.args[position]
+        return next((kw.value for kw in node.keywords if kw.arg == keyword), None)
+
+    @staticmethod
+    def _pytest_argnames(node: Optional[ast.expr]) -> List[str]:
+        """`"a, b"` or `("a", "b")` -> ["a", "b"]; anything dynamic -> []."""
+        if isinstance(node, ast.Constant) and isinstance(node.value, str):
+            return [name.strip() for name in node.value.split(",") if name.strip()]
+        if isinstance(node, (ast.List, ast.Tuple)) and all(
+            isinstance(e, ast.Constant) and isinstance(e.value, str) for e in node.elts
+        ):
+            return [e.value for e in node.elts]
+        return []
+
+    def _check_parametrize_row(self, row: ast.expr, expected: int) -> None:
+        """One argvalues row against the number of argnames."""
+        if isinstance(row, ast.Call) and self._qualified_name(row.func) == "pytest.param":
+            values = row.args
+        elif isinstance(row, (ast.List, ast.Tuple)):
+            values = row.elts
+        else:
+            # `(3)` among `(1, 2)` rows — a missed comma, or simply too few values.
+            message = self.STC203.format(found="a single parenthesized value", expected=expected)
+            self._check_candidate(row, in_membership=False, message=message)
+            return
+        if any(isinstance(v, ast.Starred) for v in values) or len(values) == expected:
+            return
+        found = f"{len(values)} value{'' if len(values) == 1 else 's'}"
+        self._report_node(row, self.STC203.format(found=found, expected=expected))
+
+    def _collect_web_apps(self) -> FrozenSet[str]:
+        """Names assigned anywhere from an app or router factory, e.g. `app = Flask(__name__)`."""
+        names = set()
+        for node in ast.walk(self.tree):
+            if isinstance(node, ast.Assign):
+                targets, value = node.targets, node.value
+            elif isinstance(node, ast.AnnAssign) and node.value is not None:
+                targets, value = [node.target], node.value
+            else:
+                continue
+            if isinstance(value, ast.Call) and self._qualified_name(value.func) in self.WEB_APP_FACTORIES:
+                names.update(t.id for t in targets if isinstance(t, ast.Name))
+        return frozenset(names)
+
+    def _check_web_call(self, node: ast.Call) -> None:
+        """
+        Route decorators and registration methods on a known app or router,
+        the factories themselves (`APIRouter(tags=...)`) and Starlette/FastAPI
+        route constructors.
+        """
+        func = node.func
+        qualname = self._qualified_name(func)
+        on_app = (
+            isinstance(func, ast.Attribute)
+            and func.attr in self.WEB_APP_METHODS
+            and isinstance(func.value, ast.Name)
+            and func.value.id in self.web_apps
+        )
+        if not on_app and qualname not in self.WEB_APP_FACTORIES | self.WEB_ROUTE_CONSTRUCTORS:
+            return
+        for kw in node.keywords:
+            if kw.arg == "methods":
+                self._check_pack_argument(node, kw.value, self.STC301)
+            elif kw.arg in self.WEB_SEQUENCE_KEYWORDS:
+                self._check_pack_argument(node, kw.value, self.STC302.format(kw.arg))
+
+    def _check_web_view(self, node: ast.ClassDef) -> None:
+        """`methods = ("POST")` on a Flask class-based view."""
+        if not any(self._qualified_name(base) in self.WEB_VIEW_BASES for base in node.bases):
+            return
+        for name, value in self._simple_assignments(node.body):
+            if name == "methods":
+                self._check_pack_value(value, self.STC301)
+
+    def _check_cli_call(self, node: ast.Call) -> None:
+        """
+        argparse `add_argument(choices=...)` and `add_parser(aliases=...)` on
+        any receiver once the module imports argparse (parsers, groups and
+        subparsers are all passed around too freely to track), `click.Choice`
+        and `multiple=True` click option defaults.
+        """
+        func = node.func
+        qualname = self._qualified_name(func)
+        if qualname in self.CLICK_CHOICE_TYPES:
+            choices = self._positional_or_keyword(node, 0, "choices")
+            if choices is not None:
+                self._check_pack_argument(node, choices, self.STC402)
+        elif qualname in self.CLICK_OPTION_FACTORIES:
+            multiple = next((kw.value for kw in node.keywords if kw.arg == "multiple"), None)
+            if isinstance(multiple, ast.Constant) and multiple.value is True:
+                for kw in node.keywords:
+                    if kw.arg == "default":
+                        self._check_pack_argument(node, kw.value, self.STC403)
+        elif isinstance(func, ast.Attribute) and func.attr in self.ARGPARSE_SEQUENCE_KEYWORDS and self._imports_argparse():
+            for kw in node.keywords:
+                if kw.arg in self.ARGPARSE_SEQUENCE_KEYWORDS[func.attr]:
+                    self._check_pack_argument(node, kw.value, self.STC401.format(kw.arg))
+
+    def _imports_argparse(self) -> bool:
+        return any(name == "argparse" or name.startswith("argparse.") for name in self.import_aliases.values())
+
+    def _check_pack_argument(self, call: ast.Call, value: ast.expr, message: str) -> None:
+        if isinstance(value, ast.Tuple) or self._infer_kind(value) == "container":
+            return
+        self._check_call_argument(call, value, self._pack_message(message, value))
+
     # ------------------------------------------------------------------
     # Core check
     # ------------------------------------------------------------------
 
-    def _check_candidate(self, node: ast.expr, in_membership: bool) -> None:
-        if not isinstance(node, ast.expr):
+    @staticmethod
+    def _is_string_literal(node: Optional[ast.expr]) -> bool:
+        if isinstance(node, ast.Constant) and isinstance(node.value, str):
+            return True
+        return isinstance(node, ast.JoinedStr)  # f-string
+
+    def _check_iterated(self, node: ast.expr) -> None:
+        if self._is_string_literal(node):
+            self._check_candidate(node, in_membership=False, message=self.STC002)
+
+    def _check_candidate(self, node: ast.expr, in_membership: bool, message: Optional[str] = None) -> None:
+        # Each node is reported once; the first (most specific) rule to reach
+        # it wins, e.g. class-body shape checks run before `visit_Assign`.
+        if node in self.reported_nodes:
             return
+        violation_idx = self._find_candidate_violation(node, in_membership)
+        if violation_idx is not None:
+            self.reported_nodes.add(node)
+            self._report(violation_idx, message or self.STC001)
+
+    def _report(self, tok_idx: int, message: str) -> None:
+        tok = self.tokens[tok_idx]
+        self.violations.append((tok.start[0], tok.start[1], message, type(self)))
+
+    def _report_node(self, node: ast.AST, message: str) -> None:
+        self.violations.append((node.lineno, node.col_offset, message, type(self)))
+
+    def _source_segment(self, node: ast.expr) -> str:
+        segment = ast.get_source_segment(self.source, node)
+        return segment if segment is not None else ast.unparse(node)
+
+    def _find_candidate_violation(self, node: ast.expr, in_membership: bool) -> Optional[int]:
+        """
+        Return the index of the `(` token wrapping `node` when the parentheses
+        look like a missed one-tuple, or None when the node is not a candidate.
+        """
+        if not isinstance(node, ast.expr):
+            return None
 
         if isinstance(node, ast.IfExp):
-            return
+            return None
 
         # BoolOp (and/or): type checkers already flag `x in (a and b)` as a
         # type error (bool isn't iterable). No need to double-warn.
         if isinstance(node, ast.BoolOp):
-            return
+            return None
 
         # BinOp: excluded in assignment context (legitimate grouping), but
         # flagged in membership — `x in (a + b)` looks like a missed comma.
         if isinstance(node, ast.BinOp) and not in_membership:
-            return
+            return None
 
         # For BinOp nodes the paren span will naturally contain operators at
         # depth 0 (the +, -, etc. that are part of the expression). We must
@@ -87,19 +1955,45 @@ class SingleTupleChecker(ast.NodeVisitor):
         # tells us this is one node, so we trust end_idx rather than rescanning.
         skip_span_check = isinstance(node, ast.BinOp)
 
+        span = self._node_token_span(node)
+        if span is None:
+            return None
+
+        return self._check_violation(*span, skip_span_check)
+
+    def _node_token_span(self, node: ast.AST) -> Optional[Tuple[int, int]]:
+        """Return the (first, last) token indexes covered by `node`."""
         start_idx = self._find_token_idx(node.lineno, node.col_offset, exact=True)
         if start_idx is None:
-            return
+            return None
 
         end_lineno = getattr(node, "end_lineno", node.lineno)
         end_col = getattr(node, "end_col_offset", node.col_offset)
         after_end_idx = self._find_token_idx(end_lineno, end_col, exact=False)
         end_idx = len(self.tokens) - 1 if after_end_idx is None else after_end_idx - 1
+        return start_idx, end_idx
 
-        violation_idx = self._check_violation(start_idx, end_idx, skip_span_check)
-        if violation_idx is not None:
-            v_tok = self.tokens[violation_idx]
-            self.violations.append((v_tok.start[0], v_tok.start[1], self.STC001, type(self)))
+    def _string_literal_tokens(self, node: ast.expr) -> list:
+        """
+        Return the literal tokens making up a string node — more than one
+        means implicit concatenation. Strings nested inside f-string
+        replacement fields (3.12+ tokenizer) are not counted.
+        """
+        span = self._node_token_span(node)
+        if span is None:
+            return []
+        literals = []
+        fstring_depth = 0
+        for tok in self.tokens[span[0]:span[1] + 1]:
+            if tok.type == _FSTRING_START:
+                if fstring_depth == 0:
+                    literals.append(tok)
+                fstring_depth += 1
+            elif tok.type == _FSTRING_END:
+                fstring_depth -= 1
+            elif tok.type == tokenize.STRING and fstring_depth == 0:
+                literals.append(tok)
+        return literals
 
     # ------------------------------------------------------------------
     # Token helpers
@@ -141,6 +2035,11 @@ class SingleTupleChecker(ast.NodeVisitor):
                     return i
         return None
 
+    def _parens_span_lines(self, open_idx: int) -> bool:
+        """True when the `(` at `open_idx` and its `)` sit on different lines — line wrapping."""
+        close_idx = self._find_matching_paren(open_idx)
+        return close_idx is not None and self.tokens[close_idx].start[0] != self.tokens[open_idx].start[0]
+
     def _span_has_comma(self, open_idx: int, close_idx: int) -> bool:
         depth = 0
         for i in range(open_idx + 1, close_idx):
@@ -157,19 +2056,30 @@ class SingleTupleChecker(ast.NodeVisitor):
         """
         Return True if the span contains more than one string token at depth 0,
         indicating an implicit string concatenation that needs the parens.
+        f-strings count once each, as in `_string_literal_tokens`, so 3.12+
+        tokenization gives the same answer as earlier versions.
         """
         depth = 0
+        fstring_depth = 0
         string_count = 0
         for i in range(open_idx + 1, close_idx):
             tok = self.tokens[i]
-            if tok.string == "(":
+            if tok.type == _FSTRING_START:
+                if fstring_depth == 0 and depth == 0:
+                    string_count += 1
+                fstring_depth += 1
+            elif tok.type == _FSTRING_END:
+                fstring_depth -= 1
+            elif fstring_depth:
+                continue
+            elif tok.string == "(":
                 depth += 1
             elif tok.string == ")":
                 depth -= 1
             elif tok.type == tokenize.STRING and depth == 0:
                 string_count += 1
-                if string_count > 1:
-                    return True
+            if string_count > 1:
+                return True
         return False
 
     def _span_is_single_expression(self, open_idx: int, close_idx: int) -> bool:
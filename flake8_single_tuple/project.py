@@ -0,0 +1,120 @@
+import ast
+import os
+from typing import Callable, Dict, List, Optional, Tuple
+
+# (tree, lines) -> {module-level name: defining line}
+BindingCollector = Callable[[ast.AST, List[str]], Dict[str, int]]
+
+
+class ProjectIndex:
+    """
+    Module-level bindings to parenthesized strings across every `.py` file
+    under a project root, plus the `from ... import` re-exports needed to
+    follow a name back to the module that defines it.
+
+    Built once per root and cached for the life of the process.
+    """
+
+    _cache: Dict[str, "ProjectIndex"] = {}
+
+    # Directories that hold third-party or generated code, never project modules.
+    SKIPPED_DIRECTORIES = frozenset({
+        "venv", "env", "site-packages", "node_modules", "build", "dist", "__pycache__", "__pypackages__",
+    })
+
+    def __init__(self, root: str, collect: BindingCollector):
+        self.root = os.path.abspath(root)
+        self.paths: Dict[str, str] = {}
+        self.bindings: Dict[str, Dict[str, int]] = {}
+        self.reexports: Dict[str, Dict[str, str]] = {}
+        for path in self._python_files():
+            module = self.module_name(path)
+            if module is None:
+                continue
+            try:
+                with open(path, encoding="utf-8") as f:
+                    lines = f.readlines()
+                tree = ast.parse("".join(lines), filename=path)
+            except (OSError, SyntaxError, UnicodeDecodeError, ValueError):
+                continue
+            self.paths[module] = path
+            self.bindings[module] = collect(tree, lines)
+            self.reexports[module] = self._collect_reexports(tree, module, path)
+
+    @classmethod
+    def for_root(cls, root: str, collect: BindingCollector) -> "ProjectIndex":
+        key = os.path.abspath(root)
+        if key not in cls._cache:
+            cls._cache[key] = cls(key, collect)
+        return cls._cache[key]
+
+    def _python_files(self) -> List[str]:
+        found: List[str] = []
+        for dirpath, dirnames, filenames in os.walk(self.root):
+            dirnames[:] = sorted(d for d in dirnames if not self._skip_directory(dirpath, d))
+            found.extend(os.path.join(dirpath, name) for name in sorted(filenames) if name.endswith(".py"))
+        return found
+
+    def _skip_directory(self, dirpath: str, name: str) -> bool:
+        """
+        Hidden directories (`.venv`, `.tox`, `.git`), the usual build and
+        dependency directories, and any virtualenv (one with a `pyvenv.cfg`).
+        """
+        if name.startswith(".") or name in self.SKIPPED_DIRECTORIES or name.endswith(".egg-info"):
+            return True
+        return os.path.exists(os.path.join(dirpath, name, "pyvenv.cfg"))
+
+    def module_name(self, path: str) -> Optional[str]:
+        """`<root>/pkg/constants.py` -> `pkg.constants`; `pkg/__init__.py` -> `pkg`."""
+        rel = os.path.relpath(os.path.abspath(path), self.root)
+        if rel.startswith(os.pardir) or not rel.endswith(".py"):
+            return None
+        parts = rel[:-3].split(os.sep)
+        if parts[-1] == "__init__":
+            parts.pop()
+        return ".".join(parts) or None
+
+    @staticmethod
+    def absolute_module(module: str, path: str, level: int, target: Optional[str]) -> Optional[str]:
+        """Resolve `from <level dots><target> import ...` as seen from `module`."""
+        if not level:
+            return target
+        package = module.split(".")
+        if os.path.basename(path) != "__init__.py":
+            package.pop()
+        if level - 1 > len(package):
+            return None
+        base = package[:len(package) - (level - 1)]
+        if target:
+            base.append(target)
+        return ".".join(base) or None
+
+    def _collect_reexports(self, tree: ast.AST, module: str, path: str) -> Dict[str, str]:
+        reexports: Dict[str, str] = {}
+        for stmt in tree.body:
+            if not isinstance(stmt, ast.ImportFrom):
+                continue
+            source = self.absolute_module(module, path, stmt.level, stmt.module)
+            if source is None:
+                continue
+            for alias in stmt.names:
+                reexports[alias.asname or alias.name] = f"{source}.{alias.name}"
+        return reexports
+
+    def resolve(self, dotted: str) -> Optional[Tuple[str, int]]:
+        """
+        Follow a fully qualified name such as `pkg.constants.ALLOWED` through
+        re-exports to its defining binding. Returns (path, line) or None.
+        """
+        seen = set()
+        while dotted not in seen:
+            seen.add(dotted)
+            module, _, name = dotted.rpartition(".")
+            if module not in self.paths:
+                return None
+            if name in self.bindings[module]:
+                return self.paths[module], self.bindings[module][name]
+            if name not in self.reexports[module]:
+                return None
+            dotted = self.reexports[module][name]
+        return None
@@ -1,6 +1,11 @@
 import ast
+import os
+import sys
+import tempfile
+import textwrap
 import unittest
 from flake8_single_tuple.plugin import SingleTupleChecker
+from flake8_single_tuple.project import ProjectIndex
 
 
 class TestSingleTupleChecker(unittest.TestCase):
@@ -171,6 +176,1758 @@ class TestSingleTupleChecker(unittest.TestCase):
         errors = self.run_checker('x = ((i for i in y))')
         self.assertEqual(len(errors), 0)
 
+    # ------------------------------------------------------------------
+    # ITERATION CONTEXTS — STC002
+    # ------------------------------------------------------------------
+
+    def test_for_loop_string_violation(self):
+        """for ext in (".py") iterates the characters of ".py"."""
+        errors = self.run_checker('for ext in (".py"): pass')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC002", errors[0][2])
+
+    def test_async_for_string_violation(self):
+        code = 'async def f():\n    async for c in ("abc"): pass'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC002", errors[0][2])
+
+    def test_comprehension_string_violation(self):
+        errors = self.run_checker('x = [c for c in ("abc")]')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC002", errors[0][2])
+
+    def test_yield_from_string_violation(self):
+        errors = self.run_checker('def f():\n    yield from ("abc")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC002", errors[0][2])
+
+    def test_starred_string_violation(self):
+        errors = self.run_checker('f(*("abc"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC002", errors[0][2])
+
+    def test_for_loop_valid_tuple(self):
+        errors = self.run_checker('for ext in (".py",): pass')
+        self.assertEqual(len(errors), 0)
+
+    def test_for_loop_name_not_flagged(self):
+        """Parenthesized names are ordinary grouping in iteration contexts."""
+        errors = self.run_checker('for c in (items): pass')
+        self.assertEqual(len(errors), 0)
+
+    def test_for_loop_unparenthesized_string_not_flagged(self):
+        """Iterating a bare string is deliberate — no parens, no missed comma."""
+        errors = self.run_checker('for c in "abc": pass')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # ITERABLE-CONSUMING CALLABLES — STC003
+    # ------------------------------------------------------------------
+
+    def test_builtin_consumer_violation(self):
+        """set(("admin")) builds {"a", "d", "m", "i", "n"}."""
+        errors = self.run_checker('x = set(("admin"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC003", errors[0][2])
+        self.assertIn("`set`", errors[0][2])
+
+    def test_str_join_violation(self):
+        errors = self.run_checker('x = ", ".join(("abc"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`str.join`", errors[0][2])
+
+    def test_dict_fromkeys_violation(self):
+        errors = self.run_checker('x = dict.fromkeys(("k"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`dict.fromkeys`", errors[0][2])
+
+    def test_zip_any_position_violation(self):
+        errors = self.run_checker('x = zip(keys, ("v"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC003", errors[0][2])
+
+    def test_module_attribute_consumer_violation(self):
+        code = 'import random\nx = random.choice(("red"))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`random.choice`", errors[0][2])
+
+    def test_import_alias_resolved(self):
+        """`import itertools as it` — each parenthesized argument is reported."""
+        code = 'import itertools as it\nx = it.chain(("a"), ("b"))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 2)
+        self.assertIn("`itertools.chain`", errors[0][2])
+
+    def test_from_import_alias_resolved(self):
+        code = 'from collections import Counter as C\nx = C(("word"))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`collections.Counter`", errors[0][2])
+
+    def test_consumer_keyword_argument_violation(self):
+        errors = self.run_checker('x = enumerate(iterable=("x"))')
+        self.assertEqual(len(errors), 1)
+
+    def test_consumer_unparenthesized_string_not_flagged(self):
+        """sorted("abc") — the call's own parens are not a missed tuple."""
+        errors = self.run_checker('x = sorted("abc")')
+        self.assertEqual(len(errors), 0)
+
+    def test_consumer_valid_tuple(self):
+        errors = self.run_checker('x = set(("admin",))')
+        self.assertEqual(len(errors), 0)
+
+    def test_consumer_non_iterable_position_not_flagged(self):
+        """filter's first argument is the predicate, not the iterable."""
+        errors = self.run_checker('x = filter(("abc"), items)')
+        self.assertEqual(len(errors), 0)
+
+    def test_unknown_callable_not_flagged(self):
+        errors = self.run_checker('x = my_func(("abc"))')
+        self.assertEqual(len(errors), 0)
+
+    def test_shadowed_builtin_consumer_not_flagged(self):
+        """A module-level `def set(x)` is not the builtin."""
+        errors = self.run_checker('def set(x):\n    return x\nset(("admin"))\n')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # COLLECTION MUTATION — STC004
+    # ------------------------------------------------------------------
+
+    def test_extend_violation(self):
+        """items.extend(("foo")) appends "f", "o", "o"."""
+        errors = self.run_checker('items.extend(("foo"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC004", errors[0][2])
+        self.assertIn("adds 3 characters, not 1 item", errors[0][2])
+
+    def test_set_update_violation(self):
+        errors = self.run_checker('seen.update(("foo"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC004", errors[0][2])
+
+    def test_extendleft_single_character_message(self):
+        errors = self.run_checker('dq.extendleft(("x"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("adds 1 character, not 1 item", errors[0][2])
+
+    def test_unbound_counter_update_violation(self):
+        """collections.Counter.update(c, ("word")) — the iterable is the second argument."""
+        code = 'import collections\ncollections.Counter.update(c, ("word"))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("adds 4 characters", errors[0][2])
+
+    def test_augassign_add_violation(self):
+        errors = self.run_checker('items += ("foo")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC004", errors[0][2])
+
+    def test_augassign_fstring_message(self):
+        errors = self.run_checker('items += (f"{x}")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("adds each character", errors[0][2])
+
+    def test_mutation_valid_tuple(self):
+        errors = self.run_checker('items.extend(("foo",))')
+        self.assertEqual(len(errors), 0)
+
+    def test_mutation_unparenthesized_not_flagged(self):
+        """items.extend("foo") — no wrapping parens to point at."""
+        errors = self.run_checker('items.extend("foo")')
+        self.assertEqual(len(errors), 0)
+
+    def test_augassign_other_operator_not_flagged(self):
+        errors = self.run_checker('x *= (2)')
+        self.assertEqual(len(errors), 0)
+
+    def test_augassign_onto_string_not_flagged(self):
+        """`+=` onto a str concatenates; the parens only wrap the line."""
+        code = 'reason = "x"\nreason += (\n    "more text"\n)\nself.msg: str = ""\nself.msg += ("y")\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_augassign_onto_list_still_flagged(self):
+        errors = self.run_checker('items = []\nitems += ("foo")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC004", errors[0][2])
+
+    # ------------------------------------------------------------------
+    # CONTAINER CONSTRUCTORS ON A BARE STRING — STC005
+    # ------------------------------------------------------------------
+
+    def test_tuple_constructor_violation(self):
+        """tuple("foo") is ("f", "o", "o"), not ("foo",)."""
+        errors = self.run_checker('x = tuple("foo")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC005", errors[0][2])
+        self.assertIn('did you mean `("foo",)`', errors[0][2])
+
+    def test_set_constructor_suggestion(self):
+        errors = self.run_checker('x = set("admin")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn('did you mean `{"admin"}`', errors[0][2])
+
+    def test_frozenset_constructor_suggestion(self):
+        errors = self.run_checker("x = frozenset('rw')")
+        self.assertEqual(len(errors), 1)
+        self.assertIn("did you mean `frozenset({'rw'})`", errors[0][2])
+
+    def test_list_constructor_bytes_violation(self):
+        errors = self.run_checker('x = list(b"key")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn('did you mean `[b"key"]`', errors[0][2])
+
+    def test_constructor_reported_at_call(self):
+        errors = self.run_checker('x = tuple("foo")')
+        self.assertEqual(errors[0][:2], (1, 4))
+
+    def test_constructor_parenthesized_reported_once(self):
+        """set(("admin")) is left to STC003."""
+        errors = self.run_checker('x = set(("admin"))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC003", errors[0][2])
+
+    def test_shadowed_constructor_not_flagged(self):
+        code = 'def tuple(x):\n    return x\ny = tuple("foo")'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_constructor_character_class_not_flagged(self):
+        """Deliberate character sets, e.g. ipaddress and _pyio."""
+        code = (
+            "HEX = frozenset('0123456789ABCDEFabcdef')\n"
+            'MODES = set("axrwb+t")\n'
+            "DRIVES = set('abcdefghijklmnopqrstuvwxyz')\n"
+            'VOWELS = set("aeiou")\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_constructor_ascending_words_violation(self):
+        """Words whose letters happen to ascend are still words."""
+        code = 'a = set("first")\nb = set("empty")\nc = list("begin")\nd = set("ghost")\n'
+        errors = self.run_checker(code)
+        self.assertEqual([e[0] for e in errors], [1, 2, 3, 4])
+        self.assertTrue(all("STC005" in e[2] for e in errors))
+
+    def test_constructor_hyphenated_word_violation(self):
+        errors = self.run_checker('x = set("read-only")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC005", errors[0][2])
+
+    def test_constructor_fstring_violation(self):
+        errors = self.run_checker('x = set(f"{prefix}_admin")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn('did you mean `{f"{prefix}_admin"}`', errors[0][2])
+
+    def test_constructor_non_literal_not_flagged(self):
+        errors = self.run_checker('x = list(name)')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # DESTRUCTURING ASSIGNMENT — STC006
+    # ------------------------------------------------------------------
+
+    def test_destructuring_violation(self):
+        """a, b = ("xy") silently binds a="x", b="y"."""
+        errors = self.run_checker('a, b = ("xy")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC006", errors[0][2])
+        self.assertIn("(2 targets, 2 characters)", errors[0][2])
+
+    def test_destructuring_single_target(self):
+        errors = self.run_checker('first, = ("k")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("(1 target, 1 character)", errors[0][2])
+
+    def test_destructuring_arity_mismatch(self):
+        """host, port = ("localhost") raises — the message shows why."""
+        errors = self.run_checker('host, port = ("localhost")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("(2 targets, 9 characters)", errors[0][2])
+
+    def test_destructuring_starred_target(self):
+        errors = self.run_checker('[head, *rest] = ("abc")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("(at least 1 target, 3 characters)", errors[0][2])
+
+    def test_destructuring_fstring_length_unknown(self):
+        errors = self.run_checker('a, b = (f"{x}")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("(2 targets)", errors[0][2])
+
+    def test_destructuring_valid_tuple(self):
+        errors = self.run_checker('first, = ("k",)')
+        self.assertEqual(len(errors), 0)
+
+    def test_destructuring_unparenthesized_not_flagged(self):
+        """a, b = "xy" — deliberate string unpacking."""
+        errors = self.run_checker('a, b = "xy"')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # PRINTF-STYLE FORMATTING — STC007
+    # ------------------------------------------------------------------
+
+    def test_printf_single_directive_violation(self):
+        """"value: %s" % (value) breaks as soon as value is a tuple."""
+        errors = self.run_checker('msg = "value: %s" % (value)')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC007", errors[0][2])
+        self.assertIn("1 directive", errors[0][2])
+
+    def test_printf_multiple_directives_violation(self):
+        errors = self.run_checker('msg = "%s-%s" % (pair)')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("2 directives", errors[0][2])
+
+    def test_printf_star_width_counts_as_directive(self):
+        errors = self.run_checker('msg = "%*d" % (n)')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("2 directives", errors[0][2])
+
+    def test_printf_string_operand_violation(self):
+        errors = self.run_checker('msg = "hello %s" % ("world")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC007", errors[0][2])
+
+    def test_printf_valid_tuple(self):
+        errors = self.run_checker('msg = "value: %s" % (value,)')
+        self.assertEqual(len(errors), 0)
+
+    def test_printf_unparenthesized_not_flagged(self):
+        errors = self.run_checker('msg = "value: %s" % value')
+        self.assertEqual(len(errors), 0)
+
+    def test_printf_binop_operand_not_flagged(self):
+        """"%s" % (a + b) — parens are needed for precedence."""
+        errors = self.run_checker('msg = "%s" % (a + b)')
+        self.assertEqual(len(errors), 0)
+
+    def test_printf_mapping_key_not_flagged(self):
+        errors = self.run_checker('msg = "%(name)s" % (params)')
+        self.assertEqual(len(errors), 0)
+
+    def test_printf_no_directives_not_flagged(self):
+        """Literal percent signs only — nothing to format."""
+        errors = self.run_checker('msg = "100%%" % (x)')
+        self.assertEqual(len(errors), 0)
+
+    def test_modulo_arithmetic_not_flagged(self):
+        errors = self.run_checker('x = n % (m)')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # IMPLICIT CONCATENATION IN MEMBERSHIP / COLLECTIONS — STC008
+    # ------------------------------------------------------------------
+
+    def test_membership_implicit_join_violation(self):
+        """x in ("foo" "bar") is a substring search in "foobar"."""
+        errors = self.run_checker('if x in ("foo" "bar"): pass')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC008", errors[0][2])
+        self.assertIn("membership test", errors[0][2])
+
+    def test_membership_implicit_join_reported_at_second_literal(self):
+        errors = self.run_checker('if x in ("foo" "bar"): pass')
+        self.assertEqual(errors[0][:2], (1, 15))
+
+    def test_membership_lhs_implicit_join_violation(self):
+        errors = self.run_checker('if ("a" "b") in x: pass')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC008", errors[0][2])
+
+    def test_membership_multiline_implicit_join_violation(self):
+        code = 'if x in (\n    "foo"\n    "bar"\n): pass'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC008", errors[0][2])
+
+    def test_list_element_implicit_join_violation(self):
+        errors = self.run_checker('methods = ["GET" "POST", "PUT"]')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("collection literal", errors[0][2])
+
+    def test_tuple_element_parenthesized_implicit_join_violation(self):
+        errors = self.run_checker('methods = (("GET" "POST"), "PUT")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC008", errors[0][2])
+
+    def test_set_element_implicit_join_violation(self):
+        errors = self.run_checker('methods = {"GET" "POST"}')
+        self.assertEqual(len(errors), 1)
+
+    def test_collection_one_per_line_missing_comma_violation(self):
+        """"staff" "guest" is one element when a line lacks its comma."""
+        code = 'ALLOWED = [\n    "admin",\n    "staff"\n    "guest",\n]'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (4, 4))
+        self.assertIn("STC008", errors[0][2])
+
+    def test_collection_wrapped_long_string_not_flagged(self):
+        """Implicit joins split across lines are the usual long-string idiom."""
+        code = 'x = [\n    "long part one "\n    "long part two",\n]'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_collection_option_table_wrapped_help_not_flagged(self):
+        """distutils-style option rows: short values and a wrapped help string."""
+        code = "opts = [\n    ('optimize=', 'O',\n     \"long text \"\n     \"more text\"),\n]"
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_collection_mixed_elements_wrapped_string_not_flagged(self):
+        code = 'x = [\n    "admin",\n    NAME,\n    "staff "\n    "guest",\n]'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_fstring_join_single_report(self):
+        """Same result whether or not the tokenizer splits f-strings (3.12+)."""
+        errors = self.run_checker('if x in (f"{a}" f"{b}"): pass')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC008", errors[0][2])
+
+    def test_collection_parenthesized_wrapped_string_not_flagged(self):
+        code = 'x = [\n    "short",\n    (\n        "long part one "\n        "long part two"\n    ),\n]'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_collection_multiline_siblings_not_flagged(self):
+        code = 'x = [\n    "one "\n    "two",\n    "three "\n    "four",\n]'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_assignment_implicit_join_still_exempt(self):
+        errors = self.run_checker('x = ("foo" "bar")')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # ACCIDENTAL TRAILING-COMMA TUPLES — STC009 / STC010 / STC011
+    # ------------------------------------------------------------------
+
+    def test_trailing_comma_assignment_violation(self):
+        """self.timeout = 30, stores (30,)."""
+        errors = self.run_checker('self.timeout = 30,')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC009", errors[0][2])
+
+    def test_trailing_comma_reported_at_comma(self):
+        errors = self.run_checker('name = "foo",')
+        self.assertEqual(errors, [(1, 12, SingleTupleChecker.STC009)])
+
+    def test_trailing_comma_annotated_assignment_violation(self):
+        errors = self.run_checker('timeout: int = 30,')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC009", errors[0][2])
+
+    def test_trailing_comma_return_violation(self):
+        errors = self.run_checker('def f():\n    return result,')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC010", errors[0][2])
+
+    def test_trailing_comma_expression_statement_violation(self):
+        errors = self.run_checker('print("x"),')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC011", errors[0][2])
+
+    def test_trailing_comma_parenthesized_element_violation(self):
+        errors = self.run_checker('x = (a),')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC009", errors[0][2])
+
+    def test_explicit_one_tuple_not_flagged(self):
+        errors = self.run_checker('x = (a,)')
+        self.assertEqual(len(errors), 0)
+
+    def test_explicit_one_tuple_return_not_flagged(self):
+        errors = self.run_checker('def f():\n    return (result,)')
+        self.assertEqual(len(errors), 0)
+
+    def test_unpacking_target_not_flagged(self):
+        errors = self.run_checker('a, = b,')
+        self.assertEqual(len(errors), 0)
+
+    def test_multi_element_tuple_not_flagged(self):
+        errors = self.run_checker('x = a, b,')
+        self.assertEqual(len(errors), 0)
+
+    def test_starred_one_tuple_not_flagged(self):
+        """`*items,` builds a tuple from an iterable; the comma is required."""
+        errors = self.run_checker('x = *a,\ndef f():\n    return *a,\n')
+        self.assertEqual(len(errors), 0)
+
+    def test_tuple_annotated_assignment_not_flagged(self):
+        errors = self.run_checker('x: Tuple[int] = 1,')
+        self.assertEqual(len(errors), 0)
+
+    def test_tuple_annotated_return_not_flagged(self):
+        errors = self.run_checker('def f() -> tuple[int]:\n    return x,')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # SIBLING SHAPE IN LIST / TUPLE / SET LITERALS — STC012
+    # ------------------------------------------------------------------
+
+    def test_list_shape_outlier_violation(self):
+        """("guest") is a string among (name, level) pairs."""
+        code = 'roles = [("admin", 1), ("user", 2), ("guest")]'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC012", errors[0][2])
+        self.assertIn("2-tuple siblings", errors[0][2])
+
+    def test_parametrize_table_shape_outlier_violation(self):
+        errors = self.run_checker('cases = [("a",), ("b")]')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("1-tuple siblings", errors[0][2])
+
+    def test_set_shape_outlier_non_string_violation(self):
+        errors = self.run_checker('pairs = {(1, 2), (3, 4), (5)}')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC012", errors[0][2])
+
+    def test_tuple_shape_outlier_violation(self):
+        errors = self.run_checker('pairs = (("a", 1), (name))')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC012", errors[0][2])
+
+    def test_shape_consistent_list_not_flagged(self):
+        errors = self.run_checker('roles = [("admin", 1), ("user", 2)]')
+        self.assertEqual(len(errors), 0)
+
+    def test_shape_minority_tuples_not_flagged(self):
+        """Mostly scalars — no tuple shape to be consistent with."""
+        errors = self.run_checker('x = [("a"), ("b"), ("c", 1)]')
+        self.assertEqual(len(errors), 0)
+
+    def test_shape_unparenthesized_outlier_not_flagged(self):
+        errors = self.run_checker('x = [("a", 1), "b"]')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # SIBLING SHAPE IN DICT VALUES — STC013
+    # ------------------------------------------------------------------
+
+    def test_dict_value_shape_outlier_violation(self):
+        """("POST") is a string while the other value is a tuple of methods."""
+        code = 'x = {"read": ("GET", "HEAD"), "write": ("POST")}'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC013", errors[0][2])
+        self.assertIn("2-tuple siblings", errors[0][2])
+
+    def test_dict_value_shape_outlier_reported_at_paren(self):
+        code = 'x = {"a": (1, 2), "b": (3)}'
+        errors = self.run_checker(code)
+        self.assertEqual(errors[0][:2], (1, 23))
+
+    def test_dict_call_keyword_shape_outlier_violation(self):
+        code = 'x = dict(read=("GET", "HEAD"), write=("POST"))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC013", errors[0][2])
+
+    def test_dict_value_shape_consistent_not_flagged(self):
+        code = 'x = {"read": ("GET", "HEAD"), "write": ("POST",)}'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_dict_scalar_values_not_flagged(self):
+        code = 'x = {"a": (1), "b": (2), "c": (3, 4)}'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_dict_unpacking_entry_ignored(self):
+        code = 'x = {"a": (1, 2), **(other)}'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_shadowed_dict_call_not_flagged(self):
+        code = 'dict = make_dict\nx = dict(a=(1, 2), b=(3))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # SIBLING SHAPE IN CLASS BODIES — STC014
+    # ------------------------------------------------------------------
+
+    def test_enum_member_shape_outlier_violation(self):
+        code = (
+            'from enum import Enum\n'
+            'class Perm(Enum):\n'
+            '    READ = ("r", 4)\n'
+            '    WRITE = ("w")\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC014", errors[0][2])
+        self.assertIn("2-tuple siblings", errors[0][2])
+
+    def test_enum_member_non_string_outlier_violation(self):
+        code = (
+            'import enum\n'
+            'class Level(enum.IntEnum):\n'
+            '    LOW = (1, "low")\n'
+            '    HIGH = (LIMIT)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC014", errors[0][2])
+
+    def test_config_class_shape_outlier_violation(self):
+        code = (
+            'class Config:\n'
+            '    READ = ("GET", "HEAD")\n'
+            '    WRITE = ("POST", "PUT")\n'
+            '    DELETE = ("DELETE")\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC014", errors[0][2])
+
+    def test_config_class_needs_clear_majority(self):
+        """A plain class with one tuple attribute has no dominant shape."""
+        code = (
+            'class Config:\n'
+            '    READ = ("GET", "HEAD")\n'
+            '    TIMEOUT = (30)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_namedtuple_fields_not_compared(self):
+        """NamedTuple fields have different types by design."""
+        code = (
+            'from typing import NamedTuple\n'
+            'class Cfg(NamedTuple):\n'
+            '    hosts: tuple = ("a", "b")\n'
+            '    ports: tuple = ("c", "d")\n'
+            '    port: int = (8080)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_namedtuple_field_checked_against_annotation(self):
+        code = (
+            'from typing import NamedTuple\n'
+            'class Cfg(NamedTuple):\n'
+            '    hosts: tuple[str, ...] = ("a")\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC019", errors[0][2])
+
+    def test_class_shape_consistent_not_flagged(self):
+        code = (
+            'from enum import Enum\n'
+            'class Perm(Enum):\n'
+            '    READ = ("r", 4)\n'
+            '    WRITE = ("w", 2)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_plain_class_string_attribute_still_stc001(self):
+        errors = self.run_checker('class C:\n    name = ("foo")\n')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC001", errors[0][2])
+
+    # ------------------------------------------------------------------
+    # SIBLING SHAPE IN MATCH CASES — STC015
+    # ------------------------------------------------------------------
+
+    @unittest.skipIf(sys.version_info < (3, 10), "match statement requires Python 3.10")
+    def test_case_value_group_violation(self):
+        """case ("c") matches the string "c", never a one-tuple subject."""
+        code = (
+            'match key:\n'
+            '    case ("a", "b"): pass\n'
+            '    case ("c"): pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(errors, [(3, 9, SingleTupleChecker.STC015.format(2))])
+
+    @unittest.skipIf(sys.version_info < (3, 10), "match statement requires Python 3.10")
+    def test_case_capture_group_violation(self):
+        code = (
+            'match key:\n'
+            '    case (a, b): pass\n'
+            '    case (x): pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(errors, [(3, 9, SingleTupleChecker.STC015_CAPTURE.format(2))])
+
+    @unittest.skipIf(sys.version_info < (3, 10), "match statement requires Python 3.10")
+    def test_case_wildcard_group_not_flagged(self):
+        code = (
+            'match key:\n'
+            '    case ("a", "b"): pass\n'
+            '    case (_): pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    @unittest.skipIf(sys.version_info < (3, 10), "match statement requires Python 3.10")
+    def test_case_one_element_sequences_not_flagged(self):
+        code = (
+            'match key:\n'
+            '    case ("a",): pass\n'
+            '    case ["b"]: pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    @unittest.skipIf(sys.version_info < (3, 10), "match statement requires Python 3.10")
+    def test_case_unparenthesized_value_not_flagged(self):
+        code = (
+            'match key:\n'
+            '    case ("a", "b"): pass\n'
+            '    case "c": pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    @unittest.skipIf(sys.version_info < (3, 10), "match statement requires Python 3.10")
+    def test_case_groups_without_sequences_not_flagged(self):
+        code = (
+            'match key:\n'
+            '    case ("a"): pass\n'
+            '    case ("b"): pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # CALL-SITE ARGUMENT SHAPE ACROSS THE MODULE — STC016
+    # ------------------------------------------------------------------
+
+    def test_call_site_keyword_outlier_violation(self):
+        code = (
+            'register(perms=("read", "write"))\n'
+            'register(perms=("read", "admin"))\n'
+            'register(perms=("admin"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][0], 3)
+        self.assertIn("STC016", errors[0][2])
+        self.assertIn("`register` elsewhere (e.g. line 1)", errors[0][2])
+
+    def test_call_site_positional_outlier_violation(self):
+        code = (
+            'import heapq\n'
+            'heapq.heappush(h, (prio, item))\n'
+            'heapq.heappush(h, (item))\n'
+            'heapq.heappush(h, (0, other))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (3, 18))
+        self.assertIn("2-tuple passed to `heapq.heappush`", errors[0][2])
+
+    def test_call_site_without_majority_not_flagged(self):
+        code = (
+            'register(perms=("read", "write"))\n'
+            'register(perms=("admin"))\n'
+            'register(perms=(other))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_call_site_own_parens_not_flagged(self):
+        """register(name) — the call's parentheses are not a group."""
+        code = (
+            'register((1, 2))\n'
+            'register((3, 4))\n'
+            'register(name)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_call_site_different_callees_not_compared(self):
+        code = (
+            'a.add((1, 2))\n'
+            'a.add((3, 4))\n'
+            'b.add((5))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # USE SITES OF NAMES BOUND TO PARENTHESIZED STRINGS — STC017
+    # ------------------------------------------------------------------
+
+    def test_binding_membership_use_violation(self):
+        code = 'ALLOWED = ("admin")\n\ndef check(role):\n    return role in ALLOWED\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 2)
+        use = [e for e in errors if "STC017" in e[2]]
+        self.assertEqual(len(use), 1)
+        self.assertEqual(use[0][:2], (4, 19))
+        self.assertIn("`ALLOWED` is a parenthesized string from line 1", use[0][2])
+        self.assertIn("substring search", use[0][2])
+
+    def test_binding_parenthesized_use_reported_once(self):
+        """`role in (ALLOWED)` is STC017, not STC017 plus STC001 at the parens."""
+        errors = self.run_checker('ALLOWED = ("admin")\nif role in (ALLOWED): pass\n')
+        self.assertEqual([e[:2] for e in errors if e[0] == 2], [(2, 12)])
+        self.assertIn("STC017", errors[0][2])
+
+    def test_binding_definition_mentions_use(self):
+        code = 'ALLOWED = ("admin")\nif role in ALLOWED: pass\n'
+        errors = self.run_checker(code)
+        definition = [e for e in errors if "STC001" in e[2]]
+        self.assertEqual(len(definition), 1)
+        self.assertIn("`ALLOWED` is used as a container on line 2", definition[0][2])
+
+    def test_binding_loop_use_violation(self):
+        code = 'EXTS = (".py")\nfor ext in EXTS: pass\n'
+        errors = self.run_checker(code)
+        use = [e for e in errors if "STC017" in e[2]]
+        self.assertEqual(len(use), 1)
+        self.assertIn("loop iterates its characters", use[0][2])
+
+    def test_binding_consumer_use_violation(self):
+        code = 'ROLES = ("admin")\nx = set(ROLES)\n'
+        errors = self.run_checker(code)
+        use = [e for e in errors if "STC017" in e[2]]
+        self.assertEqual(len(use), 1)
+        self.assertIn("`set` consumes its characters", use[0][2])
+
+    def test_binding_attribute_use_violation(self):
+        code = 'self.allowed = ("admin")\nok = role not in self.allowed\n'
+        errors = self.run_checker(code)
+        use = [e for e in errors if "STC017" in e[2]]
+        self.assertEqual(len(use), 1)
+        self.assertIn("`self.allowed`", use[0][2])
+
+    def test_binding_rebound_name_not_tracked(self):
+        code = 'ALLOWED = ("admin")\nALLOWED = ("admin", "user")\nif role in ALLOWED: pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual([e for e in errors if "STC017" in e[2]], [])
+
+    def test_binding_local_not_visible_in_other_function(self):
+        """`ALLOWED` in g() is a global or import, not f()'s local."""
+        code = (
+            'def f():\n'
+            '    ALLOWED = ("admin")\n'
+            '    return role in ALLOWED\n'
+            'def g():\n'
+            '    return role in ALLOWED\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual([e[0] for e in errors if "STC017" in e[2]], [3])
+
+    def test_binding_module_name_used_in_function(self):
+        code = 'ALLOWED = ("admin")\ndef g():\n    return role in ALLOWED\n'
+        errors = self.run_checker(code)
+        self.assertEqual([e[0] for e in errors if "STC017" in e[2]], [3])
+
+    def test_binding_self_attribute_scoped_to_class(self):
+        code = (
+            'class A:\n'
+            '    def __init__(self):\n'
+            '        self.allowed = ("admin")\n'
+            'class B:\n'
+            '    def check(self, role):\n'
+            '        return role in self.allowed\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual([e for e in errors if "STC017" in e[2]], [])
+
+    def test_binding_valid_tuple_not_tracked(self):
+        code = 'ALLOWED = ("admin",)\nif role in ALLOWED: pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_binding_unparenthesized_string_not_tracked(self):
+        """ALLOWED = "admin" is plainly a string — substring tests may be deliberate."""
+        code = 'ALLOWED = "admin"\nif role in ALLOWED: pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # MEMBERSHIP INFERENCE — known containers are not reported
+    # ------------------------------------------------------------------
+
+    def test_membership_known_list_name_not_flagged(self):
+        errors = self.run_checker('items = ["a", "b"]\nif x in (items): pass')
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_attribute_dict_not_flagged(self):
+        code = 'class C:\n    def __init__(self):\n        self.cache = {}\n    def f(self, key):\n        return key in (self.cache)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_annotated_parameter_not_flagged(self):
+        code = 'def f(x, allowed: set[str]):\n    return x in (allowed)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_optional_annotation_not_flagged(self):
+        code = 'from typing import List, Optional\nnames: Optional[List[str]] = None\nok = x in (names)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_annotated_return_not_flagged(self):
+        code = 'def get_allowed() -> list[str]:\n    return []\nok = x in (get_allowed())\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_builtin_call_not_flagged(self):
+        errors = self.run_checker('ok = x in (set(names))')
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_binop_of_lists_not_flagged(self):
+        errors = self.run_checker('a = [1]\nb = [2]\nok = x in (a + b)')
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_comprehension_result_not_flagged(self):
+        errors = self.run_checker('names = {u.name for u in users}\nok = x in (names)')
+        self.assertEqual(len(errors), 0)
+
+    def test_membership_known_string_still_flagged(self):
+        errors = self.run_checker('def f(x, role: str):\n    return x in (role)\n')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC001", errors[0][2])
+
+    def test_membership_unknown_name_still_flagged(self):
+        errors = self.run_checker('ok = x in (items)')
+        self.assertEqual(len(errors), 1)
+
+    def test_membership_conflicting_bindings_still_flagged(self):
+        """Bound to a list in one place and an unknown call in another."""
+        errors = self.run_checker('items = []\nitems = load()\nok = x in (items)')
+        self.assertEqual(len(errors), 1)
+
+    def test_membership_shadowed_builtin_still_flagged(self):
+        errors = self.run_checker('def set(x):\n    return x\nok = x in (set(names))')
+        self.assertEqual(len(errors), 1)
+
+    # ------------------------------------------------------------------
+    # TUPLE-KEYED CONTAINERS — STC018
+    # ------------------------------------------------------------------
+
+    def test_tuple_keyed_subscript_violation(self):
+        code = 'ROUTES = {("GET", "/"): index}\nhandler = ROUTES[("GET")]\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (2, 17))
+        self.assertIn("STC018", errors[0][2])
+        self.assertIn("`ROUTES` is keyed by tuples (line 1)", errors[0][2])
+
+    def test_tuple_keyed_local_not_visible_in_other_function(self):
+        code = (
+            'def f():\n'
+            '    d = {("a", "b"): 1}\n'
+            '    return d[("a")]\n'
+            'def g(d):\n'
+            '    return d[("a")]\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual([e[0] for e in errors if "STC018" in e[2]], [3])
+
+    def test_tuple_keyed_get_violation(self):
+        code = 'd = {(k, v): 1 for k, v in pairs}\nx = d.get(("a"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC018", errors[0][2])
+
+    def test_tuple_keyed_set_membership_violation(self):
+        code = 'seen = {(1, 2), (3, 4)}\nok = (key) in seen\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC018", errors[0][2])
+
+    def test_tuple_keys_from_subscript_stores(self):
+        code = 'd = {}\nd[a, b] = 1\nd.pop(("a"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC018", errors[0][2])
+
+    def test_tuple_keyed_valid_lookup_not_flagged(self):
+        code = 'd = {("a", "b"): 1}\nx = d[("a", "b")]\ny = d.get(key)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_scalar_keyed_membership_lhs_not_flagged(self):
+        """(key) in d — harmless when d is known to hold scalar keys."""
+        code = 'd = {"a": 1, "b": 2}\nok = ("a") in d\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_unknown_container_lhs_still_flagged(self):
+        errors = self.run_checker('ok = ("a") in d')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC001", errors[0][2])
+
+    def test_rebound_container_not_tracked(self):
+        code = 'd = {("a", "b"): 1}\nd = load()\nx = d[("a")]\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # ANNOTATION-DRIVEN CHECKS — STC019
+    # ------------------------------------------------------------------
+
+    def test_annotated_assignment_violation(self):
+        errors = self.run_checker('TIMEOUTS: tuple[int, ...] = (30)')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC019", errors[0][2])
+        self.assertIn("annotated `tuple[int, ...]`", errors[0][2])
+
+    def test_annotated_string_assignment_reported_once(self):
+        errors = self.run_checker('from typing import Sequence\nROLES: Sequence[str] = ("admin")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC019", errors[0][2])
+
+    def test_annotated_default_violation(self):
+        errors = self.run_checker('def f(tags: tuple[str, ...] = ("x")):\n    pass')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC019", errors[0][2])
+
+    def test_annotated_kwonly_default_violation(self):
+        errors = self.run_checker('def f(*, tags: frozenset[str] = (DEFAULT)):\n    pass')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC019", errors[0][2])
+
+    def test_annotated_return_violation(self):
+        code = 'def roles() -> tuple[str, ...]:\n    return ("admin")\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (2, 11))
+        self.assertIn("STC019", errors[0][2])
+
+    def test_annotated_yield_violation(self):
+        code = (
+            'from typing import Iterator\n'
+            'def rows() -> Iterator[tuple[str, int]]:\n'
+            '    yield ("a", 1)\n'
+            '    yield (name)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][0], 4)
+        self.assertIn("annotated `tuple[str, int]`; expected a 2-tuple", errors[0][2])
+        self.assertNotIn("(x,)", errors[0][2])
+
+    def test_generator_annotation_yield_violation(self):
+        code = 'def rows() -> "Generator[Tuple[str], None, None]":\n    yield (name)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("annotated `Tuple[str]`", errors[0][2])
+
+    def test_optional_annotation_violation(self):
+        errors = self.run_checker('from typing import Optional\nx: Optional[tuple[str, ...]] = ("a")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC019", errors[0][2])
+
+    def test_annotated_valid_tuple_not_flagged(self):
+        code = 'def roles() -> tuple[str, ...]:\n    return ("admin",)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_annotated_container_value_not_flagged(self):
+        errors = self.run_checker('x: Sequence[str] = (["a"])')
+        self.assertEqual(len(errors), 0)
+
+    def test_nested_function_return_not_checked(self):
+        code = (
+            'def outer() -> tuple[str, ...]:\n'
+            '    def inner():\n'
+            '        return ("x")\n'
+            '    return inner(),\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual([e for e in errors if "STC019" in e[2]], [])
+
+    def test_annotated_wrapped_call_not_flagged(self):
+        code = 'x: tuple[int, int] = (\n    compute_pair(a, b)\n)\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_annotated_wrapped_name_not_flagged(self):
+        code = 'x: tuple[str, ...] = (\n    DEFAULT_NAMES\n)\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_annotated_yield_wrapped_call_not_flagged(self):
+        code = (
+            'from typing import Iterator\n'
+            'def rows() -> Iterator[tuple[str, int]]:\n'
+            '    yield (\n'
+            '        make_pair()\n'
+            '    )\n'
+        )
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_optional_annotation_none_not_flagged(self):
+        code = (
+            'from typing import Optional\n'
+            'X: Optional[tuple[str, ...]] = (None)\n'
+            'def f() -> Optional[tuple[str, ...]]:\n'
+            '    return (None)\n'
+        )
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_annotated_iterable_call_not_flagged(self):
+        self.assertEqual(self.run_checker('x: Iterable[int] = (range(10))'), [])
+
+    def test_non_sequence_annotation_not_flagged(self):
+        code = 'def f() -> int:\n    return (1)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # SIGNATURE-DRIVEN CALL-SITE CHECKS — STC020
+    # ------------------------------------------------------------------
+
+    def test_signature_positional_violation(self):
+        code = (
+            'from typing import Iterable\n'
+            'def notify(channels: Iterable[str]):\n'
+            '    pass\n'
+            'notify(("email"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (4, 7))
+        self.assertIn("STC020", errors[0][2])
+        self.assertIn("`notify` parameter `channels: Iterable[str]`", errors[0][2])
+
+    def test_signature_keyword_violation(self):
+        code = 'def grant(user, roles: tuple[str, ...] = ()):\n    pass\ngrant(u, roles=("admin"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC020", errors[0][2])
+
+    def test_signature_method_violation(self):
+        code = (
+            'class Mailer:\n'
+            '    def send(self, to: list[str]):\n'
+            '        pass\n'
+            'mailer = Mailer()\n'
+            'mailer.send(("a@example.com"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`mailer.send` parameter `to: list[str]`", errors[0][2])
+
+    def test_signature_method_on_self_violation(self):
+        code = (
+            'class Mailer:\n'
+            '    def send(self, to: list[str]):\n'
+            '        pass\n'
+            '    def notify(self):\n'
+            '        self.send(("a@example.com"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`self.send` parameter `to: list[str]`", errors[0][2])
+
+    def test_signature_staticmethod_on_class_violation(self):
+        code = (
+            'class Mailer:\n'
+            '    @staticmethod\n'
+            '    def send(to: list[str]):\n'
+            '        pass\n'
+            'Mailer.send(("a@example.com"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+
+    def test_signature_method_unknown_receiver_not_flagged(self):
+        code = (
+            'class Mailer:\n'
+            '    def send(self, to: list[str]):\n'
+            '        pass\n'
+            'sock.send(("data"))\n'
+            'requests.send(("data"))\n'
+            'mailer = Mailer()\n'
+            'mailer = connect()\n'
+            'mailer.send(("a@example.com"))\n'
+        )
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_signature_mapping_parameter_not_flagged(self):
+        code = (
+            'from typing import Mapping\n'
+            'def render(context: dict[str, str], extra: Mapping[str, str]):\n'
+            '    pass\n'
+            'render(("page"), ("footer"))\n'
+        )
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_signature_dataclass_field_violation(self):
+        code = (
+            'from dataclasses import dataclass\n'
+            '@dataclass(frozen=True)\n'
+            'class Job:\n'
+            '    name: str\n'
+            '    tags: frozenset[str] = frozenset()\n'
+            'job = Job("build", ("ci"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`Job` parameter `tags: frozenset[str]`", errors[0][2])
+
+    def test_signature_namedtuple_field_violation(self):
+        code = (
+            'from typing import NamedTuple, Sequence\n'
+            'class Route(NamedTuple):\n'
+            '    path: str\n'
+            '    methods: Sequence[str]\n'
+            'r = Route(path="/", methods=("GET"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC020", errors[0][2])
+
+    def test_signature_string_parameter_not_flagged(self):
+        code = 'def greet(name: str):\n    pass\ngreet(("bob"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_signature_unannotated_parameter_not_flagged(self):
+        code = 'def notify(channels):\n    pass\nnotify(("email"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_signature_valid_tuple_not_flagged(self):
+        code = 'def notify(channels: list[str]):\n    pass\nnotify(("email",))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    def test_signature_redefined_function_not_flagged(self):
+        code = (
+            'def notify(channels: list[str]):\n    pass\n'
+            'def notify(channel: str):\n    pass\n'
+            'notify(("email"))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # DUNDER SEQUENCES — STC021 / STC022 / STC023
+    # ------------------------------------------------------------------
+
+    def test_dunder_all_violation(self):
+        errors = self.run_checker('__all__ = ("public_fn")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC021", errors[0][2])
+        self.assertIn("import *", errors[0][2])
+
+    def test_dunder_all_augmented_violation(self):
+        errors = self.run_checker('__all__ = ["a"]\n__all__ += ("helper")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC021", errors[0][2])
+
+    def test_dunder_slots_violation(self):
+        errors = self.run_checker('class C:\n    __slots__ = ("name")\n')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC022", errors[0][2])
+
+    def test_dunder_match_args_violation(self):
+        errors = self.run_checker('class P:\n    __match_args__ = ("x")\n')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC023", errors[0][2])
+        self.assertIn("TypeError", errors[0][2])
+
+    def test_dunder_non_string_value_violation(self):
+        errors = self.run_checker('__all__ = (PUBLIC_NAME)')
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][2], "STC021 `__all__` is parenthesized but not a tuple; did you mean `(x,)`?")
+
+    def test_dunder_annotated_violation(self):
+        errors = self.run_checker('__all__: tuple = ("public_fn")')
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC021", errors[0][2])
+
+    def test_dunder_valid_tuple_not_flagged(self):
+        errors = self.run_checker('__all__ = ("public_fn",)')
+        self.assertEqual(len(errors), 0)
+
+    def test_dunder_trailing_comma_tuple_not_flagged(self):
+        """__slots__ = "name", is the intended one-tuple, not STC009."""
+        errors = self.run_checker('class C:\n    __slots__ = "name",\n')
+        self.assertEqual(len(errors), 0)
+
+    def test_dunder_concatenation_not_flagged(self):
+        errors = self.run_checker('__all__ = (base.__all__ + ["extra"])')
+        self.assertEqual(len(errors), 0)
+
+    # ------------------------------------------------------------------
+    # DB-API PARAMETERS — STC024
+    # ------------------------------------------------------------------
+
+    def test_dbapi_execute_violation(self):
+        code = 'cursor.execute("SELECT * FROM t WHERE id = ?", (user_id))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (1, 47))
+        self.assertIn("STC024", errors[0][2])
+        self.assertIn("the SQL has 1 placeholder; did you mean `(user_id,)`?", errors[0][2])
+
+    def test_dbapi_format_style_violation(self):
+        code = 'conn.execute("UPDATE t SET a = %s WHERE id = %s", (pair))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("2 placeholders", errors[0][2])
+
+    def test_dbapi_string_parameter_violation(self):
+        code = 'cur.execute("SELECT * FROM users WHERE name = ?", ("alice"))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("did you mean `(\"alice\",)`?", errors[0][2])
+
+    def test_dbapi_executemany_row_violation(self):
+        code = 'cur.executemany("INSERT INTO t VALUES (?)", [(a,), (b)])'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`executemany`", errors[0][2])
+
+    def test_dbapi_valid_tuple_not_flagged(self):
+        code = 'cursor.execute("SELECT * FROM t WHERE id = ?", (user_id,))'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_dbapi_named_placeholders_not_flagged(self):
+        code = 'cursor.execute("SELECT * FROM t WHERE id = :id", (params))'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_dbapi_placeholder_in_quotes_ignored(self):
+        code = 'cursor.execute("SELECT \'?\' FROM t", (x))'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_dbapi_postgres_cast_not_named(self):
+        code = 'cursor.execute("SELECT %s::int", (x))'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+
+    def test_dbapi_non_literal_sql_not_flagged(self):
+        errors = self.run_checker('cursor.execute(query, (user_id))')
+        self.assertEqual(errors, [])
+
+
+class TestProjectMode(unittest.TestCase):
+    """Cross-module resolution of constants with `--single-tuple-project-root`."""
+
+    def setUp(self):
+        tmp = tempfile.TemporaryDirectory()
+        self.addCleanup(tmp.cleanup)
+        self.root = tmp.name
+        SingleTupleChecker.project_root = self.root
+        self.addCleanup(setattr, SingleTupleChecker, "project_root", None)
+
+    def write(self, relpath, code):
+        path = os.path.join(self.root, relpath)
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        with open(path, "w") as f:
+            f.write(textwrap.dedent(code))
+        return path
+
+    def run_checker(self, relpath):
+        path = os.path.join(self.root, relpath)
+        with open(path) as f:
+            lines = f.readlines()
+        checker = SingleTupleChecker(ast.parse("".join(lines)), lines, filename=path)
+        return [(line, col, msg) for line, col, msg, _ in checker.run()]
+
+    def test_from_import_use_violation(self):
+        self.write("app/constants.py", 'ALLOWED_ROLES = ("admin")\n')
+        self.write("app/views.py", """\
+            from app.constants import ALLOWED_ROLES
+
+            def check(role):
+                return role in ALLOWED_ROLES
+        """)
+        errors = self.run_checker("app/views.py")
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (4, 19))
+        self.assertIn("STC017", errors[0][2])
+        self.assertIn(f"from {os.path.join('app', 'constants.py')} line 1", errors[0][2])
+
+    def test_relative_import_use_violation(self):
+        self.write("app/__init__.py", "")
+        self.write("app/constants.py", 'EXTS = (".py")\n')
+        self.write("app/scan.py", "from .constants import EXTS\nfor ext in EXTS: pass\n")
+        errors = self.run_checker("app/scan.py")
+        self.assertEqual(len(errors), 1)
+        self.assertIn("loop iterates its characters", errors[0][2])
+
+    def test_module_attribute_use_violation(self):
+        self.write("settings.py", 'ADMINS = ("root")\n')
+        self.write("main.py", "import settings as s\nok = user in s.ADMINS\n")
+        errors = self.run_checker("main.py")
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`s.ADMINS`", errors[0][2])
+
+    def test_reexport_chain_resolved(self):
+        self.write("pkg/__init__.py", "from .constants import ALLOWED\n")
+        self.write("pkg/constants.py", '\nALLOWED = ("admin")\n')
+        self.write("main.py", "from pkg import ALLOWED\nok = user in ALLOWED\n")
+        errors = self.run_checker("main.py")
+        self.assertEqual(len(errors), 1)
+        self.assertIn(f"from {os.path.join('pkg', 'constants.py')} line 2", errors[0][2])
+
+    def test_valid_tuple_constant_not_flagged(self):
+        self.write("constants.py", 'ALLOWED = ("admin",)\n')
+        self.write("main.py", "from constants import ALLOWED\nok = user in ALLOWED\n")
+        self.assertEqual(self.run_checker("main.py"), [])
+
+    def test_function_local_binding_not_exported(self):
+        self.write("constants.py", 'def f():\n    ALLOWED = ("admin")\n    return ALLOWED\n')
+        self.write("main.py", "from constants import ALLOWED\nok = user in ALLOWED\n")
+        self.assertEqual(self.run_checker("main.py"), [])
+
+    def test_shadowed_import_not_flagged(self):
+        self.write("constants.py", 'ALLOWED = ("admin")\n')
+        self.write("main.py", 'from constants import ALLOWED\nALLOWED = ["admin"]\nok = user in ALLOWED\n')
+        self.assertEqual(self.run_checker("main.py"), [])
+
+    def test_virtualenv_and_build_directories_skipped(self):
+        self.write("app/constants.py", 'ALLOWED = ("admin")\n')
+        self.write("py312/pyvenv.cfg", "home = /usr/bin\n")
+        self.write("py312/lib/python3.12/site-packages/requests/__init__.py", "")
+        self.write("build/lib/app/constants.py", 'ALLOWED = ("admin")\n')
+        self.write("node_modules/pkg/setup.py", "")
+        index = ProjectIndex(self.root, lambda tree, lines: {})
+        self.assertEqual(sorted(index.paths), ["app.constants"])
+
+    def test_project_mode_off_by_default(self):
+        SingleTupleChecker.project_root = None
+        self.write("constants.py", 'ALLOWED = ("admin")\n')
+        self.write("main.py", "from constants import ALLOWED\nok = user in ALLOWED\n")
+        self.assertEqual(self.run_checker("main.py"), [])
+
+
+class PackTestCase(unittest.TestCase):
+    """Runs the checker with the rule pack named by `pack` enabled."""
+
+    pack = ""
+
+    def setUp(self):
+        SingleTupleChecker.enabled_packs = frozenset({self.pack})
+        self.addCleanup(setattr, SingleTupleChecker, "enabled_packs", frozenset())
+
+    def run_checker(self, code, filename="stdin"):
+        tree = ast.parse(code)
+        lines = code.splitlines(keepends=True)
+        checker = SingleTupleChecker(tree, lines, filename=filename)
+        return [(line, col, msg) for line, col, msg, _ in checker.run()]
+
+
+class TestDjangoPack(PackTestCase):
+    pack = "django"
+
+    def test_meta_ordering_violation(self):
+        code = 'class Book(models.Model):\n    class Meta:\n        ordering = ("name")\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC101 Django `ordering`", errors[0][2])
+
+    def test_meta_fields_non_string_violation(self):
+        code = 'class F(forms.ModelForm):\n    class Meta:\n        fields = (EMAIL_FIELD)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(
+            errors[0][2], "STC101 Django `fields` is parenthesized but not a tuple; did you mean `(x,)`?"
+        )
+
+    def test_meta_fields_string_message_names_characters(self):
+        code = 'class Meta:\n    fields = ("email")\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("character by character", errors[0][2])
+
+    def test_unique_together_group_violation(self):
+        code = 'class Meta:\n    unique_together = (("a", "b"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (2, 22))
+        self.assertIn("STC102", errors[0][2])
+
+    def test_permissions_group_violation(self):
+        code = 'class Meta:\n    permissions = (("can_publish", "Can publish"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC102 Django `permissions`", errors[0][2])
+
+    def test_fields_all_not_flagged(self):
+        code = 'class F(forms.ModelForm):\n    class Meta:\n        fields = ("__all__")\n        exclude = ("__all__")\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_unique_together_flat_tuple_not_flagged(self):
+        code = 'class Meta:\n    unique_together = ("a", "b")\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_model_admin_violation(self):
+        code = (
+            'from django.contrib import admin\n'
+            'class BookAdmin(admin.ModelAdmin):\n'
+            '    list_display = ("title")\n'
+            '    search_fields = ("name",)\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`list_display`", errors[0][2])
+
+    def test_drf_view_permission_classes_violation(self):
+        code = 'class V(APIView):\n    permission_classes = (IsAuthenticated)\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`permission_classes`", errors[0][2])
+
+    def test_settings_module_violation(self):
+        errors = self.run_checker('INSTALLED_APPS = ("myapp")\n', filename="project/settings.py")
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC103 Django setting `INSTALLED_APPS`", errors[0][2])
+
+    def test_settings_package_violation(self):
+        errors = self.run_checker('ALLOWED_HOSTS = (HOST)\n', filename="project/settings/prod.py")
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC103", errors[0][2])
+
+    def test_non_settings_module_not_flagged(self):
+        errors = self.run_checker('ALLOWED_HOSTS = (HOST)\n', filename="project/views.py")
+        self.assertEqual(errors, [])
+
+    def test_attribute_outside_django_class_not_flagged(self):
+        errors = self.run_checker('class Config:\n    ordering = (field)\n')
+        self.assertEqual(errors, [])
+
+    def test_pack_disabled_by_default(self):
+        SingleTupleChecker.enabled_packs = frozenset()
+        code = 'class Meta:\n    ordering = (field)\n'
+        self.assertEqual(self.run_checker(code), [])
+
+
+class TestPytestPack(PackTestCase):
+    pack = "pytest"
+
+    def test_parametrize_argvalues_violation(self):
+        code = 'import pytest\n@pytest.mark.parametrize("x", ("abc"))\ndef test_x(x): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (2, 30))
+        self.assertIn("STC201", errors[0][2])
+
+    def test_parametrize_keyword_argvalues_violation(self):
+        code = 'import pytest\n@pytest.mark.parametrize(argnames="x", argvalues=(CASES))\ndef test_x(x): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC201", errors[0][2])
+
+    def test_parametrize_non_string_message_is_neutral(self):
+        """`(load_cases())` may return a list; no claim about characters."""
+        code = 'import pytest\n@pytest.mark.parametrize("x", (load_cases()))\ndef test_x(x): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(
+            errors[0][2], "STC201 parenthesized `parametrize` argvalues is not a tuple; did you mean `(x,)`?"
+        )
+
+    def test_fixture_params_string_message_names_characters(self):
+        code = 'import pytest\n@pytest.fixture(params=("sqlite"))\ndef db(request): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("each character becomes a fixture param", errors[0][2])
+
+    def test_parametrize_through_from_import_alias(self):
+        code = 'from pytest import mark as m\n@m.parametrize("x", ("abc"))\ndef test_x(x): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC201", errors[0][2])
+
+    def test_fixture_params_violation(self):
+        code = 'import pytest as pt\n@pt.fixture(params=("sqlite"))\ndef db(request): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC202", errors[0][2])
+
+    def test_parametrize_row_parenthesized_scalar_violation(self):
+        code = 'import pytest\n@pytest.mark.parametrize(("a", "b"), [(1, 2), (3)])\ndef test_x(a, b): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (2, 46))
+        self.assertIn("STC203 `parametrize` row has a single parenthesized value, but 2 argnames", errors[0][2])
+
+    def test_parametrize_row_arity_violation(self):
+        code = 'import pytest\n@pytest.mark.parametrize("a, b", [(1, 2), (3, 4, 5)])\ndef test_x(a, b): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("row has 3 values, but 2 argnames", errors[0][2])
+
+    def test_parametrize_param_row_arity_violation(self):
+        code = (
+            'import pytest\n'
+            '@pytest.mark.parametrize("a,b", [pytest.param(1, id="one")])\n'
+            'def test_x(a, b): pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("row has 1 value, but 2 argnames", errors[0][2])
+
+    def test_parametrize_valid_not_flagged(self):
+        code = (
+            'import pytest\n'
+            '@pytest.mark.parametrize("a, b", [(1, 2), pytest.param(3, 4)])\n'
+            '@pytest.mark.parametrize("x", ("abc",))\n'
+            'def test_x(a, b, x): pass\n'
+        )
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_parametrize_single_argname_scalar_rows_not_flagged(self):
+        code = 'import pytest\n@pytest.mark.parametrize("x", [(1), (2)])\ndef test_x(x): pass\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_parametrize_unparenthesized_argvalues_not_flagged(self):
+        """A bare string is rejected by pytest itself; nothing parenthesized to point at."""
+        code = 'import pytest\n@pytest.mark.parametrize("x", CASES)\ndef test_x(x): pass\n'
+        self.assertEqual(self.run_checker(code), [])
+
+
+
+class TestWebPack(PackTestCase):
+    pack = "web"
+
+    def test_flask_route_methods_violation(self):
+        code = 'from flask import Flask\napp = Flask(__name__)\n@app.route("/", methods=("GET"))\ndef index(): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (3, 24))
+        self.assertIn("STC301", errors[0][2])
+
+    def test_flask_blueprint_add_url_rule_violation(self):
+        code = 'import flask\nbp = flask.Blueprint("bp", __name__)\nbp.add_url_rule("/", view_func=v, methods=("POST"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC301", errors[0][2])
+
+    def test_flask_method_view_violation(self):
+        code = 'from flask.views import MethodView\nclass V(MethodView):\n    methods = ("POST")\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC301", errors[0][2])
+
+    def test_fastapi_tags_violation(self):
+        code = 'from fastapi import FastAPI\napp = FastAPI()\n@app.get("/users", tags=("users"))\nasync def users(): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC302 parenthesized `tags`", errors[0][2])
+        self.assertIn("character by character", errors[0][2])
+
+    def test_fastapi_router_factory_dependencies_violation(self):
+        code = 'from fastapi import APIRouter, Depends\nrouter = APIRouter(dependencies=(Depends(auth)))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][2], "STC302 parenthesized `dependencies` is not a tuple; did you mean `(x,)`?")
+
+    def test_app_created_inside_factory_function(self):
+        code = (
+            "from flask import Flask\n"
+            "def create_app():\n"
+            "    app = Flask(__name__)\n"
+            '    app.add_url_rule("/", view_func=v, methods=("GET"))\n'
+        )
+        self.assertEqual(len(self.run_checker(code)), 1)
+
+    def test_starlette_route_violation(self):
+        code = 'from starlette.routing import Route\nroutes = [Route("/", endpoint, methods=("POST"))]\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC301", errors[0][2])
+
+    def test_valid_tuple_and_list_not_flagged(self):
+        code = (
+            "from flask import Flask\n"
+            "app = Flask(__name__)\n"
+            '@app.route("/", methods=("GET",))\n'
+            "def a(): pass\n"
+            '@app.route("/b", methods=["GET", "POST"])\n'
+            "def b(): pass\n"
+        )
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_unknown_receiver_not_flagged(self):
+        code = 'client.get("/", tags=("users"))\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_pack_disabled_not_flagged(self):
+        SingleTupleChecker.enabled_packs = frozenset()
+        code = 'from flask import Flask\napp = Flask(__name__)\n@app.route("/", methods=("GET"))\ndef index(): pass\n'
+        self.assertEqual(self.run_checker(code), [])
+
+
+
+class TestCliPack(PackTestCase):
+    pack = "cli"
+
+    def test_argparse_choices_violation(self):
+        code = 'import argparse\nparser = argparse.ArgumentParser()\nparser.add_argument("--mode", choices=("fast"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (3, 38))
+        self.assertIn("STC401 parenthesized argparse `choices`", errors[0][2])
+
+    def test_argparse_group_choices_violation(self):
+        code = (
+            "from argparse import ArgumentParser\n"
+            "group = ArgumentParser().add_argument_group()\n"
+            'group.add_argument("--fmt", choices=(FORMAT))\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][2], "STC401 parenthesized argparse `choices` is not a tuple; did you mean `(x,)`?")
+
+    def test_argparse_subparser_aliases_violation(self):
+        code = 'import argparse\nsub = argparse.ArgumentParser().add_subparsers()\nsub.add_parser("run", aliases=("r"))\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("`aliases`", errors[0][2])
+
+    def test_add_argument_without_argparse_import_not_flagged(self):
+        code = 'cli.add_argument("--mode", choices=("fast"))\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_click_choice_violation(self):
+        code = 'import click\n@click.option("--fmt", type=click.Choice(("json")))\ndef main(fmt): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertEqual(errors[0][:2], (2, 41))
+        self.assertIn("STC402", errors[0][2])
+
+    def test_click_choice_in_typer_option_violation(self):
+        code = (
+            "import click\n"
+            "import typer\n"
+            'def main(fmt: str = typer.Option("json", click_type=click.Choice(("json")))): pass\n'
+        )
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC402", errors[0][2])
+
+    def test_click_multiple_default_violation(self):
+        code = 'from click import option\n@option("--tag", multiple=True, default=("latest"))\ndef main(tag): pass\n'
+        errors = self.run_checker(code)
+        self.assertEqual(len(errors), 1)
+        self.assertIn("STC403", errors[0][2])
+
+    def test_click_single_default_not_flagged(self):
+        code = 'import click\n@click.option("--tag", default=("latest"))\ndef main(tag): pass\n'
+        self.assertEqual(self.run_checker(code), [])
+
+    def test_valid_choices_not_flagged(self):
+        code = (
+            "import argparse\n"
+            "import click\n"
+            'argparse.ArgumentParser().add_argument("--mode", choices=("fast",))\n'
+            'click.Choice(["json", "yaml"])\n'
+        )
+        self.assertEqual(self.run_checker(code), [])
+
 
 if __name__ == "__main__":
     unittest.main()